@@ -45,17 +45,13 @@ fn setup(
     let mut m = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
     let mut pos = vec![];
     let mut vns = vec![];
-    for (fid, hs) in res.hs.chunks(3).enumerate() {
-        let p0 = res.ps[hs[0].tail];
-        let p1 = res.ps[hs[1].tail];
-        let p2 = res.ps[hs[2].tail];
-        let n  = res.face_normals[fid];
-        pos.push([p0.x as f32, p0.y as f32, p0.z as f32]);
-        pos.push([p1.x as f32, p1.y as f32, p1.z as f32]);
-        pos.push([p2.x as f32, p2.y as f32, p2.z as f32]);
-        vns.push([n.x as f32, n.y as f32, n.z as f32]);
-        vns.push([n.x as f32, n.y as f32, n.z as f32]);
-        vns.push([n.x as f32, n.y as f32, n.z as f32]);
+    let ps = res.positions_f32();
+    for (fid, t) in res.triangles().iter().enumerate() {
+        let n = res.face_normals[fid];
+        for &v in t.iter() {
+            pos.push(ps[v]);
+            vns.push([n.x as f32, n.y as f32, n.z as f32]);
+        }
     }
     m.insert_attribute(Mesh::ATTRIBUTE_POSITION, pos);
     m.insert_attribute(Mesh::ATTRIBUTE_NORMAL, vns);
@@ -71,26 +67,21 @@ fn setup(
 }
 
 pub fn menger_sponge(n: usize) -> Manifold {
-    let res = Manifold::new(&PS, &TS).unwrap();
+    let res = Manifold::cube(boolmesh::Vec3::ONE, true).unwrap();
     let mut holes = vec![];
     fractal(&res, &mut holes, 0., 0., 1., 1, n);
     let holes_z = compose(&holes).unwrap();
 
+    let ts = holes_z.triangles().concat();
     let rot = |rx: f64, ry: f64, rz: f64| {
-        let ts = holes_z.hs.iter().map(|h| h.tail).collect::<Vec<_>>();
-        let mut ps = holes_z.ps.clone();
-        let x = rx as boolmesh::Real;
-        let y = ry as boolmesh::Real;
-        let z = rz as boolmesh::Real;
-        let r = boolmesh::Mat3::from_euler(glam::EulerRot::XYZ, x, y, z);
-        for p in ps.iter_mut() { *p = r * *p; }
-        let mut flat = vec![];
-        for p in ps {
-            flat.push(if (p.x - 0.5).abs() < 1e-4 { 0.5 } else if (p.x + 0.5).abs() < 1e-4 { -0.5 } else { p.x as f64});
-            flat.push(if (p.y - 0.5).abs() < 1e-4 { 0.5 } else if (p.y + 0.5).abs() < 1e-4 { -0.5 } else { p.y as f64});
-            flat.push(if (p.z - 0.5).abs() < 1e-4 { 0.5 } else if (p.z + 0.5).abs() < 1e-4 { -0.5 } else { p.z as f64});
-        }
-        Manifold::new(&flat, &ts).unwrap()
+        let r = glam::DMat3::from_euler(glam::EulerRot::XYZ, rx, ry, rz);
+        let snap = |v: f64| if (v - 0.5).abs() < 1e-4 { 0.5 } else if (v + 0.5).abs() < 1e-4 { -0.5 } else { v };
+        let ps = holes_z
+            .positions_f64()
+            .into_iter()
+            .flat_map(|p| (r * glam::DVec3::from_array(p)).to_array().map(snap))
+            .collect::<Vec<_>>();
+        Manifold::new(&ps, &ts).unwrap()
     };
 
     let holes_x = rot(PI / 2., 0., 0.);
@@ -102,46 +93,21 @@ pub fn menger_sponge(n: usize) -> Manifold {
     res
 }
 
-const PS: [f64; 24] = [
-    -0.5, -0.5, -0.5,
-    -0.5, -0.5,  0.5,
-    -0.5,  0.5, -0.5,
-    -0.5,  0.5,  0.5,
-     0.5, -0.5, -0.5,
-     0.5, -0.5,  0.5,
-     0.5,  0.5, -0.5,
-     0.5,  0.5,  0.5
-];
-
-const TS: [usize; 36] = [
-    1, 0, 4, 2, 4, 0,
-    1, 3, 0, 3, 1, 5,
-    3, 2, 0, 3, 7, 2,
-    5, 4, 6, 5, 1, 4,
-    6, 4, 2, 7, 6, 2,
-    7, 3, 5, 7, 5, 6
-];
-
 pub fn compose(ms: &Vec<Manifold>) -> std::result::Result<Manifold, String> {
     let mut ps = vec![];
     let mut ts = vec![];
-    let mut offset = 0;
     for m in ms {
-        for h in m.hs.iter() { ts.push(h.tail + offset); }
-        for p in m.ps.iter() {
-            ps.push(p.x as f64);
-            ps.push(p.y as f64);
-            ps.push(p.z as f64);
-        }
-        offset += m.nv;
+        ts.extend(m.triangles().iter().flatten().map(|&i| i + ps.len() / 3));
+        ps.extend(m.positions_f64().iter().flatten());
     }
     Manifold::new(&ps, &ts)
 }
 
 pub fn fractal(hole: &Manifold, holes: &mut Vec<Manifold>, x: f64, y: f64, w: f64, depth: usize, depth_max: usize) {
     let w = w / 3.;
-    let p = hole.ps.iter().map(|p| [p.x as f64 * w + x, p.y as f64 * w + y, p.z as f64]).flatten().collect::<Vec<f64>>();
-    holes.push(Manifold::new(&p, &TS).unwrap());
+    let p = hole.positions_f64().iter().flat_map(|p| [p[0] * w + x, p[1] * w + y, p[2]]).collect::<Vec<f64>>();
+    let t = hole.triangles().concat();
+    holes.push(Manifold::new(&p, &t).unwrap());
 
     if depth == depth_max { return; }
 
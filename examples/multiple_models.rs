@@ -31,17 +31,13 @@ fn setup(
     mut mats: ResMut<Assets<StandardMaterial>>,
     mut meshes: ResMut<Assets<Mesh>>
 ) {
-    let obj_path_1 = "examples/models/gargoyle.obj";
-    let obj_path_2 = "examples/models/double-torus.obj";
-    let (m0, _) = tobj::load_obj(obj_path_1, &tobj::LoadOptions { ..Default::default() }).expect("Failed to load the first obj file");
-    let (m1, _) = tobj::load_obj(obj_path_2, &tobj::LoadOptions { ..Default::default() }).expect("Failed to load the second obj file");
+    let obj_paths = ["examples/models/gargoyle.obj", "examples/models/double-torus.obj"];
 
     let mut mfs = vec![];
-    for m in vec![&m0[0].mesh, &m1[0].mesh] {
-        mfs.push(Manifold::new(
-            &m.positions.iter().map(|&v| v as f64).collect::<Vec<_>>(),
-            &m.indices.iter().map(|&v| v as usize).collect::<Vec<_>>(),
-        ).unwrap());
+    for path in obj_paths {
+        let file = std::fs::File::open(path).expect("Failed to open the obj file");
+        let mut reader = std::io::BufReader::new(file);
+        mfs.push(Manifold::from_obj(&mut reader).expect("Failed to load the obj file"));
     }
     mfs.push(compute_boolean(&mfs[0], &mfs[1], OpType::Subtract).unwrap());
 
@@ -49,17 +45,13 @@ fn setup(
         let mut m = Mesh::new(PrimitiveTopology::TriangleList, RenderAssetUsages::default());
         let mut pos = vec![];
         let mut vns = vec![];
-        for (fid, hs) in mf.hs.chunks(3).enumerate() {
-            let p0 = mf.ps[hs[0].tail];
-            let p1 = mf.ps[hs[1].tail];
-            let p2 = mf.ps[hs[2].tail];
-            let n  = mf.face_normals[fid];
-            pos.push([p0.x as f32, p0.y as f32, p0.z as f32]);
-            pos.push([p1.x as f32, p1.y as f32, p1.z as f32]);
-            pos.push([p2.x as f32, p2.y as f32, p2.z as f32]);
-            vns.push([n.x as f32, n.y as f32, n.z as f32]);
-            vns.push([n.x as f32, n.y as f32, n.z as f32]);
-            vns.push([n.x as f32, n.y as f32, n.z as f32]);
+        let ps = mf.positions_f32();
+        for (fid, t) in mf.triangles().iter().enumerate() {
+            let n = mf.face_normals[fid];
+            for &v in t.iter() {
+                pos.push(ps[v]);
+                vns.push([n.x as f32, n.y as f32, n.z as f32]);
+            }
         }
         m.insert_attribute(Mesh::ATTRIBUTE_POSITION, pos);
         m.insert_attribute(Mesh::ATTRIBUTE_NORMAL, vns);
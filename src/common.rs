@@ -27,9 +27,15 @@ pub use precision::{Real, Vec2, Vec3, Vec4, Mat3, K_PRECISION};
 pub const K_BEST: Real = Real::MIN;
 
 
-#[derive(PartialEq)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum OpType { Add, Subtract, Intersect }
 
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BooleanOptions {
+    pub delaunay: bool, // edge-flips faces with many vertices toward constrained Delaunay
+}
+
 #[derive(Clone, Debug)]
 pub struct Half {
     pub tail: usize,
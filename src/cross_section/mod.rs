@@ -0,0 +1,145 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+mod project;
+
+use std::f64::consts::TAU;
+use crate::extrusion::{orient_profile, signed_area};
+use crate::triangulation::flat_tree::Rect;
+use crate::minkowski::minkowski_sum;
+use crate::{compute_boolean_opt, BooleanOptions, Manifold, OpType, Real, Vec2};
+
+/// Polygons with holes on the xy plane, where outer loops are ccw and holes cw.
+/// Booleans and offsets are carried out on slabs extruded from the polygons
+/// and the results are sliced back, so they share the epsilon model of the 3D booleans.
+#[derive(Clone, Debug, Default)]
+pub struct CrossSection {
+    polys: Vec<Vec<Vec2>>,
+}
+
+impl CrossSection {
+    /// Loops with less than three points are dropped, and every loop is flipped
+    /// when the total area is negative. The loops are expected not to intersect each other.
+    pub fn new(polygons: &[Vec<Vec2>]) -> Result<Self, String> {
+        Ok(Self { polys: orient_profile(polygons)? })
+    }
+
+    pub fn polygons(&self) -> &[Vec<Vec2>] { &self.polys }
+
+    pub fn is_empty(&self) -> bool { self.polys.is_empty() }
+
+    pub fn area(&self) -> Real { self.polys.iter().map(|p| signed_area(p)).sum() }
+
+    /// Min and max corners of the bounding rectangle, inverted when empty.
+    pub fn bounds(&self) -> (Vec2, Vec2) {
+        let mut r = Rect::default();
+        for p in self.polys.iter().flatten() { r.union(*p); }
+        (r.min, r.max)
+    }
+
+    /// Extrudes the polygons along the z axis from z = 0 to `height`.
+    pub fn extrude(&self, height: Real) -> Result<Manifold, String> {
+        Manifold::extrude(&self.polys, height, 0, 0., Vec2::ONE)
+    }
+
+    pub fn boolean(&self, other: &CrossSection, op: OpType) -> Result<CrossSection, String> {
+        match (self.is_empty(), other.is_empty(), op) {
+            (true, _, OpType::Add) => return Ok(other.clone()),
+            (_, true, OpType::Add | OpType::Subtract) => return Ok(self.clone()),
+            (true, _, _) | (_, true, _) => return Ok(Self::default()),
+            _ => {}
+        }
+        let m = compute_boolean_opt(&self.extrude(1.)?, &other.extrude(1.)?, op, &BooleanOptions::default())?;
+        Ok(m.map_or_else(Self::default, |m| m.slice(0.5)))
+    }
+
+    /// Grows the polygons by `delta`, or shrinks them when negative, with round joins
+    /// approximated by a polygon of `segments` sides.
+    pub fn offset(&self, delta: Real, segments: usize) -> Result<CrossSection, String> {
+        if delta == 0. || self.is_empty() { return Ok(self.clone()); }
+        if delta < 0. {
+            // erodes by removing the grown complement within an enlarged frame
+            let (lo, hi) = self.bounds();
+            let d = Vec2::splat(-2. * delta);
+            let (a, b) = (lo - d, hi + d);
+            let frame = Self::new(&[vec![a, Vec2::new(b.x, a.y), b, Vec2::new(a.x, b.y)]])?;
+            let comp = frame.boolean(self, OpType::Subtract)?;
+            return self.boolean(&comp.offset(-delta, segments)?, OpType::Subtract);
+        }
+        let n = segments.max(3);
+        let disk = (0..n)
+            .map(|i| Vec2::from_angle((TAU as Real) * i as Real / n as Real) * delta)
+            .collect::<Vec<_>>();
+        let disk = Manifold::extrude(&[disk], 0.5, 0, 0., Vec2::ONE)?;
+        Ok(minkowski_sum(&self.extrude(1.)?, &disk)?.slice(0.75))
+    }
+}
+
+impl Manifold {
+    /// Cross-section with the plane z = `height`. Vertices on the plane count as above it,
+    /// and the contours are chained through the halfedge pairs, so they are closed and ccw
+    /// around the material for a valid manifold. A contour passing a point twice, where the
+    /// surface touches itself, is split there and the slivers without area are dropped.
+    pub fn slice(&self, height: Real) -> CrossSection {
+        let above = |v: usize| self.ps[v].z >= height;
+        let point = |h: usize| {
+            let (a, b) = (self.ps[self.hs[h].tail], self.ps[self.hs[h].head]);
+            let (a, b) = if self.hs[h].tail < self.hs[h].head { (a, b) } else { (b, a) };
+            let p = a + (b - a) * ((height - a.z) / (b.z - a.z));
+            Vec2::new(p.x, p.y)
+        };
+
+        // each face crossing the plane links the halfedge going down to the pair of the one going up
+        let mut next = vec![usize::MAX; self.nh];
+        for f in 0..self.nf {
+            let mut s = usize::MAX;
+            let mut e = usize::MAX;
+            for h in f * 3..f * 3 + 3 {
+                match (above(self.hs[h].tail), above(self.hs[h].head)) {
+                    (true, false) => s = h,
+                    (false, true) => e = h,
+                    _ => {}
+                }
+            }
+            if s != usize::MAX && e != usize::MAX { next[s] = self.hs[e].pair; }
+        }
+
+        let mut polys = vec![];
+        let mut done = vec![false; self.nh];
+        for h0 in 0..self.nh {
+            if next[h0] == usize::MAX || done[h0] { continue; }
+            let mut poly = vec![];
+            let mut h = h0;
+            while h != usize::MAX && !done[h] {
+                done[h] = true;
+                poly.push(point(h));
+                h = next[h];
+            }
+            polys.extend(split_loops(&poly, self.eps));
+        }
+        CrossSection { polys }
+    }
+}
+
+// Splits a contour at the points visited twice, which appear where the surface touches itself,
+// and drops the resulting slivers with no area.
+fn split_loops(ps: &[Vec2], eps: Real) -> Vec<Vec<Vec2>> {
+    let mut res = vec![];
+    let mut cur: Vec<Vec2> = vec![];
+    for &p in ps.iter() {
+        match cur.iter().position(|q| q.distance(p) <= eps) {
+            Some(k) => {
+                let l = cur.split_off(k);
+                cur.push(l[0]);
+                res.push(l);
+            }
+            None => cur.push(p),
+        }
+    }
+    res.push(cur);
+    res.retain(|l| {
+        let len = (0..l.len()).map(|i| l[i].distance(l[(i + 1) % l.len()])).sum::<Real>();
+        l.len() >= 3 && signed_area(l).abs() > eps * len
+    });
+    res
+}
@@ -0,0 +1,110 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::collections::HashSet;
+use crate::extrusion::signed_area;
+use crate::{compute_boolean_all, det2x2, get_aa_proj_matrix, is_ccw_2d, next_of, Manifold, OpType, Vec2, Vec3};
+
+impl Manifold {
+    /// Silhouette of the manifold projected onto the plane facing `plane_normal`, as ccw outer
+    /// loops and cw holes. The plane axes are the axis-aligned projection of `get_aa_proj_matrix`
+    /// made orthonormal, so an axis-aligned normal keeps the coordinates as they are.
+    /// Each connected region of front-facing triangles is extruded from its outline and the prisms
+    /// are unioned, where a region overlapping itself in the projection is halved until it does not.
+    pub fn project(&self, plane_normal: Vec3) -> Result<Vec<Vec<Vec2>>, String> {
+        let n = plane_normal.normalize();
+        if !n.is_finite() { return Err("projection normal must be non-zero".into()); }
+        let (r1, _) = get_aa_proj_matrix(&n);
+        let u = (r1 - n * r1.dot(n)).normalize();
+        let v = n.cross(u);
+        let ps = self.ps.iter().map(|p| Vec2::new(u.dot(*p), v.dot(*p))).collect::<Vec<_>>();
+
+        // faces turning ccw in the plane, the ones seen edge-on add no area
+        let fs = (0..self.nf).filter(|&f| {
+            let t = (0..3).map(|i| ps[self.hs[f * 3 + i].tail]).collect::<Vec<_>>();
+            is_ccw_2d(&t[0], &t[1], &t[2], self.eps) > 0
+        }).collect::<Vec<_>>();
+        if fs.is_empty() { return Ok(vec![]); }
+
+        let mut ms = vec![];
+        self.cover(&fs, &ps, &mut vec![false; self.nf], &mut ms)?;
+        let min = self.eps * self.bounding_box.scale();
+        let mut res = compute_boolean_all(&ms, OpType::Add)?.slice(0.5).polygons().to_vec();
+        res.retain(|l| signed_area(l).abs() > min);
+        Ok(res)
+    }
+
+    // Appends prisms covering the projected faces, one per connected part with a simple outline.
+    // Parts overlapping themselves are halved in the face order, which keeps the halves compact.
+    fn cover(&self, fs: &[usize], ps: &[Vec2], inside: &mut [bool], ms: &mut Vec<Manifold>) -> Result<(), String> {
+        for &f in fs.iter() { inside[f] = true; }
+        let mut parts = vec![];
+        let mut seen = HashSet::new();
+        let mut traced = HashSet::new();
+        for &f0 in fs.iter() {
+            if !seen.insert(f0) { continue; }
+            let mut part = vec![];
+            let mut loops = vec![];
+            let mut stack = vec![f0];
+            while let Some(f) = stack.pop() {
+                part.push(f);
+                for h in f * 3..f * 3 + 3 {
+                    let g = self.hs[h].pair / 3;
+                    if inside[g] {
+                        if seen.insert(g) { stack.push(g); }
+                        continue;
+                    }
+                    // traces the boundary loop from h, turning around the head to the next boundary halfedge
+                    if !traced.insert(h) { continue; }
+                    let mut l = vec![];
+                    let mut e = h;
+                    loop {
+                        l.push(self.hs[e].tail);
+                        e = next_of(e);
+                        while inside[self.hs[e].pair / 3] { e = next_of(self.hs[e].pair); }
+                        if !traced.insert(e) { break; }
+                    }
+                    loops.push(l);
+                }
+            }
+            parts.push((part, loops));
+        }
+        for &f in fs.iter() { inside[f] = false; }
+
+        for (mut part, loops) in parts {
+            let mut seen = HashSet::new();
+            let once = loops.iter().flatten().all(|&i| seen.insert(i));
+            let loops = loops.iter().map(|l| l.iter().map(|&i| ps[i]).collect::<Vec<_>>()).collect::<Vec<_>>();
+            if once && !edges_meet(&loops) {
+                ms.push(Manifold::extrude(&loops, 1., 0, 0., Vec2::ONE)?);
+                continue;
+            }
+            part.sort_unstable();
+            let (a, b) = part.split_at(part.len() / 2);
+            self.cover(a, ps, inside, ms)?;
+            self.cover(b, ps, inside, ms)?;
+        }
+        Ok(())
+    }
+}
+
+// Whether two edges of the loops touch, other than neighbouring edges at their shared point.
+fn edges_meet(loops: &[Vec<Vec2>]) -> bool {
+    let mut es = vec![];
+    for (k, l) in loops.iter().enumerate() {
+        for i in 0..l.len() { es.push((k, i, l[i], l[(i + 1) % l.len()])); }
+    }
+    es.sort_by(|a, b| a.2.x.min(a.3.x).total_cmp(&b.2.x.min(b.3.x)));
+
+    let side = |p: Vec2, q: Vec2, r: Vec2| det2x2(&(q - p), &(r - p));
+    for (j, &(k0, i0, a, b)) in es.iter().enumerate() {
+        for &(k1, i1, c, d) in es[j + 1..].iter() {
+            if c.x.min(d.x) > a.x.max(b.x) { break; }
+            let n = loops[k0].len();
+            if k0 == k1 && (i1 == (i0 + 1) % n || i0 == (i1 + 1) % n) { continue; }
+            if c.y.min(d.y) > a.y.max(b.y) || a.y.min(b.y) > c.y.max(d.y) { continue; }
+            if side(a, b, c) * side(a, b, d) <= 0. && side(c, d, a) * side(c, d, b) <= 0. { return true; }
+        }
+    }
+    false
+}
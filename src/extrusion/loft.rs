@@ -0,0 +1,98 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use crate::{Manifold, Real, Vec2, Vec3, Vec3u};
+use super::{orient_profile, triangulate_profile};
+
+/// A plane spanned by the unit axes u and v, whose normal is u × v.
+#[derive(Clone, Copy, Debug)]
+pub struct Plane {
+    pub origin: Vec3,
+    pub u: Vec3,
+    pub v: Vec3,
+}
+
+impl Plane {
+    /// Plane through `origin` facing `normal`, with the u axis picked from the
+    /// world axis least aligned with the normal.
+    pub fn new(origin: Vec3, normal: Vec3) -> Self {
+        let n = normal.normalize();
+        let a = if n.x.abs() < n.y.abs().max(n.z.abs()) { Vec3::X } else { Vec3::Y };
+        let u = (a - n * a.dot(n)).normalize();
+        Self { origin, u, v: n.cross(u) }
+    }
+
+    pub fn normal(&self) -> Vec3 { self.u.cross(self.v) }
+
+    pub fn to_world(&self, p: &Vec2) -> Vec3 { self.origin + self.u * p.x + self.v * p.y }
+}
+
+// Normalized arc length parameter of each vertex of a closed loop.
+fn arc_params(ps: &[Vec3]) -> Vec<Real> {
+    let mut ts = vec![0.];
+    for i in 1..ps.len() { ts.push(ts[i - 1] + ps[i].distance(ps[i - 1])); }
+    let len = ts[ps.len() - 1] + ps[0].distance(ps[ps.len() - 1]);
+    ts.iter().map(|t| t / len).collect()
+}
+
+impl Manifold {
+    /// Lofts a closed solid through a sequence of cross-sections, each a single loop placed on
+    /// its plane. Sections may have differing vertex counts. Consecutive rings are aligned at
+    /// their closest vertices and stitched by advancing along the normalized arc length,
+    /// and both ends are capped.
+    pub fn loft(sections: &[(Plane, Vec<Vec2>)]) -> Result<Manifold, String> {
+        if sections.len() < 2 { return Err("loft requires at least two sections".into()); }
+
+        let mut rings: Vec<Vec<Vec3>> = vec![];
+        let mut caps = vec![];
+        for (pl, sec) in sections.iter() {
+            let loops = orient_profile(std::slice::from_ref(sec))?;
+            if loops.len() != 1 { return Err("loft section must be a single loop".into()); }
+            let mut ring = loops[0].iter().map(|p| pl.to_world(p)).collect::<Vec<_>>();
+            let mut loop2d = loops[0].clone();
+            if let Some(prev) = rings.last() {
+                let s = (0..ring.len())
+                    .min_by(|&a, &b| ring[a].distance(prev[0]).total_cmp(&ring[b].distance(prev[0])))
+                    .unwrap();
+                ring.rotate_left(s);
+                loop2d.rotate_left(s);
+            }
+            caps.push(loop2d);
+            rings.push(ring);
+        }
+
+        let mut oft = vec![0];
+        for r in rings.iter() { oft.push(oft.last().unwrap() + r.len()); }
+
+        let mut ts = vec![];
+        for k in 0..rings.len() - 1 {
+            let (na, nb) = (rings[k].len(), rings[k + 1].len());
+            let (ta, tb) = (arc_params(&rings[k]), arc_params(&rings[k + 1]));
+            let a = |i: usize| oft[k] + i % na;
+            let b = |j: usize| oft[k + 1] + j % nb;
+            let (mut i, mut j) = (0, 0);
+            while i < na || j < nb {
+                let next_a = if i + 1 < na { ta[i + 1] } else { 1. };
+                let next_b = if j + 1 < nb { tb[j + 1] } else { 1. };
+                if j == nb || (i < na && next_a <= next_b) {
+                    ts.push(Vec3u::new(a(i), a(i + 1), b(j)));
+                    i += 1;
+                } else {
+                    ts.push(Vec3u::new(a(i), b(j + 1), b(j)));
+                    j += 1;
+                }
+            }
+        }
+
+        let last = rings.len() - 1;
+        for t in triangulate_profile(std::slice::from_ref(&caps[0])) { ts.push(Vec3u::new(t.x, t.z, t.y)); }
+        for t in triangulate_profile(std::slice::from_ref(&caps[last])) { ts.push(t + Vec3u::splat(oft[last])); }
+
+        // sections ordered against their normals produce an inside-out mesh
+        let ps = rings.concat();
+        let vol = ts.iter().map(|t| ps[t.x].dot(ps[t.y].cross(ps[t.z]))).sum::<Real>();
+        if vol < 0. { for t in ts.iter_mut() { *t = Vec3u::new(t.x, t.z, t.y); } }
+
+        Manifold::new_impl(ps, ts, None, None)
+    }
+}
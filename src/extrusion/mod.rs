@@ -0,0 +1,119 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+pub mod loft;
+pub mod revolution;
+pub mod sweep;
+
+use crate::triangulation::triangulate_polygons;
+use crate::{det2x2, Manifold, Real, Vec2, Vec3, Vec3u};
+
+impl Manifold {
+    /// Extrudes 2D polygons along the z axis. Outer loops are expected to be ccw and holes cw,
+    /// which is enforced as a whole by flipping every loop when the total area is negative.
+    /// `n_divisions` extra layers are inserted between the caps, over which the profile rotates
+    /// by `twist_degrees` and is scaled towards `scale_top`. A zero `scale_top` yields a cone, which
+    /// needs a single loop since loops meeting at one apex would pinch it.
+    pub fn extrude(
+        polygons: &[Vec<Vec2>],
+        height: Real,
+        n_divisions: usize,
+        twist_degrees: Real,
+        scale_top: Vec2,
+    ) -> Result<Manifold, String> {
+        if height <= 0. { return Err("extrusion height must be positive".into()); }
+        let apex = scale_top == Vec2::ZERO;
+        if !apex && scale_top.min_element() <= 0. { return Err("scale_top must be positive or zero".into()); }
+
+        let polys = orient_profile(polygons)?;
+        if apex && polys.len() > 1 { return Err("a zero scale_top requires a profile with a single loop".into()); }
+        let prof = polys.concat();
+        let np = prof.len();
+        let nl = n_divisions + 2;
+
+        let mut ps = vec![];
+        for k in 0..nl {
+            let t = k as Real / (nl - 1) as Real;
+            let z = height * t;
+            if apex && k == nl - 1 { ps.push(Vec3::new(0., 0., z)); break; }
+            let s = Vec2::ONE + (scale_top - Vec2::ONE) * t;
+            let r = Vec2::from_angle((twist_degrees * t).to_radians());
+            for p in prof.iter() {
+                let q = r.rotate(*p * s);
+                ps.push(Vec3::new(q.x, q.y, z));
+            }
+        }
+
+        let vid = |k: usize, i: usize| if apex && k == nl - 1 { k * np } else { k * np + i };
+        let mut ts = vec![];
+        for k in 0..nl - 1 {
+            for_each_edge(&polys, |i, j| push_quad(&mut ts, vid(k, i), vid(k, j), vid(k + 1, j), vid(k + 1, i)));
+        }
+
+        let cap = triangulate_profile(&polys);
+        for t in cap.iter() {
+            ts.push(Vec3u::new(t.x, t.z, t.y));
+            if !apex { ts.push(*t + Vec3u::splat((nl - 1) * np)); }
+        }
+
+        Manifold::new_impl(ps, ts, None, None)
+    }
+}
+
+// Closes layers of the same profile into a manifold. The rings are stitched in order, and either
+// the first and the last layers are capped, or the last layer is connected back to the first.
+pub(crate) fn stitch_layers(
+    polys: &[Vec<Vec2>],
+    layers: Vec<Vec<Vec3>>,
+    closed: bool,
+) -> Result<Manifold, String> {
+    let nl = layers.len();
+    let np = polys.iter().map(|p| p.len()).sum::<usize>();
+    let mut ts = vec![];
+    for k in 0..if closed { nl } else { nl - 1 } {
+        let (k0, k1) = (k * np, (k + 1) % nl * np);
+        for_each_edge(polys, |i, j| push_quad(&mut ts, k0 + i, k0 + j, k1 + j, k1 + i));
+    }
+    if !closed {
+        for t in triangulate_profile(polys) {
+            ts.push(Vec3u::new(t.x, t.z, t.y));
+            ts.push(t + Vec3u::splat((nl - 1) * np));
+        }
+    }
+    Manifold::new_impl(layers.concat(), ts, None, None)
+}
+
+// Flips all loops if the profile is clockwise as a whole, and drops loops too short to bound an area.
+pub(crate) fn orient_profile(polygons: &[Vec<Vec2>]) -> Result<Vec<Vec<Vec2>>, String> {
+    let mut polys = polygons.iter().filter(|p| p.len() >= 3).cloned().collect::<Vec<_>>();
+    if polys.is_empty() { return Err("profile must contain a polygon".into()); }
+    if polys.iter().map(|p| signed_area(p)).sum::<Real>() < 0. {
+        for p in polys.iter_mut() { p.reverse(); }
+    }
+    Ok(polys)
+}
+
+pub(crate) fn signed_area(poly: &[Vec2]) -> Real {
+    (0..poly.len()).map(|i| det2x2(&poly[i], &poly[(i + 1) % poly.len()])).sum::<Real>() * 0.5
+}
+
+// Visits the edges of every loop as pairs of indices into the concatenated loops.
+pub(crate) fn for_each_edge<F>(polys: &[Vec<Vec2>], mut f: F) where F: FnMut(usize, usize) {
+    let mut oft = 0;
+    for p in polys.iter() {
+        for i in 0..p.len() { f(oft + i, oft + (i + 1) % p.len()); }
+        oft += p.len();
+    }
+}
+
+// Pushes the quad a-b-c-d as two triangles, skipping the ones collapsed by shared vertices.
+pub(crate) fn push_quad(ts: &mut Vec<Vec3u>, a: usize, b: usize, c: usize, d: usize) {
+    for t in [Vec3u::new(a, b, c), Vec3u::new(a, c, d)] {
+        if t.x != t.y && t.y != t.z && t.z != t.x { ts.push(t); }
+    }
+}
+
+// Ccw triangles of the profile, indexed into the concatenated loops.
+pub(crate) fn triangulate_profile(polys: &[Vec<Vec2>]) -> Vec<Vec3u> {
+    triangulate_polygons(polys, -1.).into_iter().map(Vec3u::from).collect()
+}
@@ -0,0 +1,66 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use crate::{Manifold, Real, Vec2, Vec3, Vec3u, K_PRECISION};
+use super::{for_each_edge, orient_profile, push_quad, triangulate_profile};
+
+impl Manifold {
+    /// Revolves 2D polygons around the z axis, where x of the profile is the radius and y is the height.
+    /// Vertices within epsilon of the axis are merged into a single vertex so no degenerate triangles
+    /// are made around it. A partial revolution (`angle_degrees` < 360) is closed by caps at both ends.
+    pub fn revolve(
+        polygons: &[Vec<Vec2>],
+        segments: usize,
+        angle_degrees: Real,
+    ) -> Result<Manifold, String> {
+        if angle_degrees <= 0. { return Err("revolution angle must be positive".into()); }
+        let mut polys = orient_profile(polygons)?;
+        let prof = polys.concat();
+        let eps = K_PRECISION * prof.iter().fold(0., |m: Real, p| m.max(p.abs().max_element()));
+        if prof.iter().any(|p| p.x < -eps) { return Err("profile must not cross the revolution axis".into()); }
+        for p in polys.iter_mut().flatten() { if p.x <= eps { p.x = 0.; } }
+        let prof = polys.concat();
+        if prof.iter().all(|p| p.x == 0.) { return Err("profile must not lie on the revolution axis".into()); }
+
+        let full = angle_degrees >= 360.;
+        let angle = if full { 360. } else { angle_degrees };
+        let ns = ((segments.max(3) as Real * angle / 360.).ceil() as usize).max(if full { 3 } else { 1 });
+        let nl = if full { ns } else { ns + 1 };
+        let np = prof.len();
+
+        // vertices on the axis are shared by all layers
+        let mut ps = vec![];
+        let mut vid = vec![usize::MAX; nl * np];
+        for (i, p) in prof.iter().enumerate() {
+            if p.x == 0. {
+                for k in 0..nl { vid[k * np + i] = ps.len(); }
+                ps.push(Vec3::new(0., 0., p.y));
+            }
+        }
+        for k in 0..nl {
+            let t = (angle * k as Real / ns as Real).to_radians();
+            let r = Vec2::from_angle(t);
+            for (i, p) in prof.iter().enumerate() {
+                if p.x == 0. { continue; }
+                vid[k * np + i] = ps.len();
+                ps.push(Vec3::new(r.x * p.x, r.y * p.x, p.y));
+            }
+        }
+
+        let mut ts = vec![];
+        for k in 0..ns {
+            let (k0, k1) = (k * np, (k + 1) % nl * np);
+            for_each_edge(&polys, |i, j| push_quad(&mut ts, vid[k0 + i], vid[k1 + i], vid[k1 + j], vid[k0 + j]));
+        }
+
+        if !full {
+            let oft = ns * np;
+            for t in triangulate_profile(&polys) {
+                ts.push(Vec3u::new(vid[t.x], vid[t.y], vid[t.z]));
+                ts.push(Vec3u::new(vid[oft + t.x], vid[oft + t.z], vid[oft + t.y]));
+            }
+        }
+
+        Manifold::new_impl(ps, ts, None, None)
+    }
+}
@@ -0,0 +1,154 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::f64::consts::PI;
+use crate::bounds::BBox;
+use crate::{compute_boolean_all, Manifold, OpType, Real, Vec2, Vec3, K_PRECISION};
+use super::{orient_profile, stitch_layers, triangulate_profile};
+
+#[derive(Clone, Debug, Default)]
+pub struct SweepOptions {
+    pub closed: bool,          // connects the end of the path back to its start without caps
+    pub resolve_overlap: bool, // detects overlap at tight bends and resolves it by boolean union
+}
+
+// Cross-section frame, where the profile x goes to r and y goes to b. A mitered section
+// is stretched by s along the in-plane bend direction m to keep the wall thickness.
+struct Frame {
+    o: Vec3,
+    r: Vec3,
+    b: Vec3,
+    m: Vec3,
+    s: Real,
+}
+
+impl Frame {
+    fn place(&self, p: &Vec2) -> Vec3 {
+        let w = self.r * p.x + self.b * p.y;
+        self.o + w + self.m * (w.dot(self.m) * (self.s - 1.))
+    }
+}
+
+// Rodrigues rotation of v around the unit axis a.
+fn rotate(v: Vec3, a: Vec3, t: Real) -> Vec3 {
+    v * t.cos() + a.cross(v) * t.sin() + a * a.dot(v) * (1. - t.cos())
+}
+
+// Axis and angle of the rotation taking the unit direction d0 to d1.
+fn bend(d0: Vec3, d1: Vec3) -> Result<(Vec3, Real), String> {
+    let c = d0.cross(d1);
+    let s = c.length();
+    let t = s.atan2(d0.dot(d1));
+    if t > PI as Real - 1e-6 { return Err("sweep path must not reverse its direction".into()); }
+    Ok((if s > 0. { c / s } else { Vec3::ZERO }, t))
+}
+
+impl Manifold {
+    /// Sweeps a 2D profile along a polyline, placing it on rotation minimizing frames.
+    /// Splines are swept by sampling them into a polyline first. Sections at inner path vertices
+    /// are mitered, and the ends are capped unless the path is closed. With `resolve_overlap`,
+    /// a path whose bends are too tight for the profile is swept piecewise and the pieces are unioned.
+    pub fn sweep(
+        profile: &[Vec2],
+        path: &[Vec3],
+        options: &SweepOptions,
+    ) -> Result<Manifold, String> {
+        let polys = orient_profile(&[profile.to_vec()])?;
+        let eps = K_PRECISION * BBox::new(None, path).scale();
+
+        let mut xs: Vec<Vec3> = vec![];
+        for p in path.iter() {
+            if xs.last().is_none_or(|q| q.distance(*p) > eps) { xs.push(*p); }
+        }
+        let closed = options.closed;
+        if closed && xs.len() > 2 && xs[0].distance(*xs.last().unwrap()) <= eps { xs.pop(); }
+        let n = xs.len();
+        if n < 2 || (closed && n < 3) { return Err("sweep path is too short".into()); }
+
+        let m = if closed { n } else { n - 1 };
+        let ds = (0..m).map(|i| (xs[(i + 1) % n] - xs[i]).normalize()).collect::<Vec<_>>();
+
+        // parallel transport of the normal along the segments
+        let d = ds[0];
+        let a = if d.x.abs() < d.y.abs().max(d.z.abs()) { Vec3::X } else { Vec3::Y };
+        let mut rs = vec![(a - d * a.dot(d)).normalize()];
+        let mut bs = vec![(Vec3::ZERO, 0.)]; // bend at each path vertex
+        for i in 1..m {
+            let (a, t) = bend(ds[i - 1], ds[i])?;
+            rs.push(rotate(rs[i - 1], a, t));
+            bs.push((a, t));
+        }
+        if !closed { bs.push((Vec3::ZERO, 0.)); }
+
+        // distributes the holonomy of a closed path as a twist over the segments
+        let mut twist = 0.;
+        if closed {
+            bs[0] = bend(ds[m - 1], ds[0])?;
+            let r = rotate(rs[m - 1], bs[0].0, bs[0].1);
+            twist = ds[0].dot(r.cross(rs[0])).atan2(r.dot(rs[0]));
+        }
+
+        let seg_frame = |i: usize, f: Real| {
+            let r = rotate(rs[i], ds[i], twist * (i as Real + f) / m as Real);
+            let o = xs[i] + (xs[(i + 1) % n] - xs[i]) * f;
+            Frame { o, r, b: ds[i].cross(r), m: Vec3::ZERO, s: 1. }
+        };
+
+        let vert_frame = |i: usize| {
+            if !closed && i == 0 { return seg_frame(0, 0.); }
+            if !closed && i == n - 1 { return seg_frame(m - 1, 1.); }
+            let ip = (i + m - 1) % m;
+            let (a, t) = bs[i];
+            let f = seg_frame(ip, 1.);
+            let r = rotate(f.r, a, t * 0.5);
+            let b = rotate(ds[ip], a, t * 0.5).cross(r);
+            Frame { o: xs[i], r, b, m: (ds[i] - ds[ip]).normalize_or_zero(), s: 1. / (t * 0.5).cos() }
+        };
+
+        let rad = polys[0].iter().fold(0., |r: Real, p| r.max(p.length()));
+        let pull = |i: usize| rad * (bs[i % n].1 * 0.5).tan();
+        let overlap = (0..m).any(|i| pull(i) + pull(i + 1) > xs[i].distance(xs[(i + 1) % n]));
+
+        if !(options.resolve_overlap && overlap) {
+            let layers = (0..n).map(|i| {
+                let f = vert_frame(i);
+                polys[0].iter().map(|p| f.place(p)).collect()
+            }).collect();
+            return stitch_layers(&polys, layers, closed);
+        }
+
+        // Straight prisms for each segment, and convex wedges of the rotating profile triangles
+        // for each joint, all of which are valid solids on their own.
+        let mut ms = vec![];
+        for i in 0..m {
+            let layers = [seg_frame(i, 0.), seg_frame(i, 1.)]
+                .iter()
+                .map(|f| polys[0].iter().map(|p| f.place(p)).collect())
+                .collect();
+            ms.push(stitch_layers(&polys, layers, false)?);
+        }
+
+        let tris = triangulate_profile(&polys);
+        for (i, &(a, t)) in bs.iter().enumerate().take(m) {
+            if t < 1e-6 { continue; }
+            let ip = (i + m - 1) % m;
+            let f = seg_frame(ip, 1.);
+            let ns = (t / (PI as Real / 16.)).ceil() as usize;
+            let frames = (0..=ns).map(|k| {
+                let u = t * k as Real / ns as Real;
+                let r = rotate(f.r, a, u);
+                Frame { o: f.o, r, b: rotate(ds[ip], a, u).cross(r), m: Vec3::ZERO, s: 1. }
+            }).collect::<Vec<_>>();
+            for w in frames.windows(2) {
+                for tri in tris.iter() {
+                    let ps = w.iter()
+                        .flat_map(|f| [tri.x, tri.y, tri.z].map(|j| f.place(&polys[0][j])))
+                        .collect::<Vec<_>>();
+                    if let Ok(h) = Manifold::hull_of_points(&ps) { ms.push(h); }
+                }
+            }
+        }
+
+        compute_boolean_all(&ms, OpType::Add)
+    }
+}
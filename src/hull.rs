@@ -0,0 +1,205 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::collections::HashMap;
+use crate::bounds::BBox;
+use crate::{Manifold, Real, Vec3, Vec3u, K_PRECISION, next_of};
+
+struct Face {
+    vs: [usize; 3],
+    n: Vec3,         // outward unit normal
+    d: Real,         // plane offset along n
+    out: Vec<usize>, // outside set, points strictly above the plane by more than eps
+    alive: bool,
+}
+
+impl Face {
+    fn new(ps: &[Vec3], vs: [usize; 3]) -> Self {
+        let n = (ps[vs[1]] - ps[vs[0]]).cross(ps[vs[2]] - ps[vs[0]]).normalize();
+        Self { vs, n, d: n.dot(ps[vs[0]]), out: vec![], alive: true }
+    }
+
+    fn dist(&self, p: &Vec3) -> Real { self.n.dot(*p) - self.d }
+
+    fn edges(&self) -> [(usize, usize); 3] {
+        let [a, b, c] = self.vs;
+        [(a, b), (b, c), (c, a)]
+    }
+}
+
+impl Manifold {
+    /// True if the mesh is a single component and no edge is reflex beyond epsilon.
+    pub fn is_convex(&self) -> bool {
+        let mut seen = vec![false; self.nf];
+        let mut stack = vec![0];
+        seen[0] = true;
+        while let Some(f) = stack.pop() {
+            for h in f * 3..f * 3 + 3 {
+                let g = self.hs[h].pair / 3;
+                if !seen[g] { seen[g] = true; stack.push(g); }
+            }
+        }
+        if seen.iter().any(|s| !s) { return false; }
+
+        self.hs.iter().enumerate().all(|(i, h)| {
+            let opp = self.hs[next_of(h.pair)].head;
+            (self.ps[opp] - self.ps[h.tail]).dot(self.face_normals[i / 3]) <= self.eps
+        })
+    }
+
+    /// Convex hull of the vertices of this manifold.
+    pub fn hull(&self) -> Result<Manifold, String> {
+        Self::hull_of_points(&self.ps)
+    }
+
+    /// Convex hull of the vertices of all given manifolds.
+    pub fn hull_all(ms: &[Manifold]) -> Result<Manifold, String> {
+        let ps = ms.iter().flat_map(|m| m.ps.iter().copied()).collect::<Vec<_>>();
+        Self::hull_of_points(&ps)
+    }
+
+    /// Convex hull of a point cloud. Duplicated points and points lying on a hull face
+    /// within the K_PRECISION-scaled epsilon are dropped, so flat regions are not over-tessellated.
+    pub fn hull_of_points(pts: &[Vec3]) -> Result<Manifold, String> {
+        let ps = pts.iter().copied().filter(|p| p.is_finite()).collect::<Vec<_>>();
+        let bb = BBox::new(None, &ps);
+        let eps = K_PRECISION * bb.scale();
+        if ps.len() < 4 || !eps.is_finite() { return Err("hull requires at least 4 points".into()); }
+
+        let tet = initial_simplex(&ps, eps)?;
+        let mut fs = vec![
+            Face::new(&ps, [tet[0], tet[1], tet[2]]),
+            Face::new(&ps, [tet[0], tet[2], tet[3]]),
+            Face::new(&ps, [tet[0], tet[3], tet[1]]),
+            Face::new(&ps, [tet[1], tet[3], tet[2]]),
+        ];
+
+        // orient the simplex outward
+        let c = tet.iter().fold(Vec3::ZERO, |acc, &i| acc + ps[i]) / 4.;
+        for f in fs.iter_mut() {
+            if f.dist(&c) > 0. { *f = Face::new(&ps, [f.vs[0], f.vs[2], f.vs[1]]); }
+        }
+
+        let rest = (0..ps.len()).filter(|i| !tet.contains(i)).collect::<Vec<_>>();
+        assign_outside(&ps, &mut fs, &[0, 1, 2, 3], &rest, eps);
+
+        let mut e2f = HashMap::new();
+        for (i, f) in fs.iter().enumerate() { for e in f.edges() { e2f.insert(e, i); } }
+
+        let mut stack = (0..fs.len()).collect::<Vec<_>>();
+        let mut mark = vec![0u8; fs.len()]; // 0: untested, 1: visible, 2: hidden
+
+        while let Some(fi) = stack.pop() {
+            if !fs[fi].alive || fs[fi].out.is_empty() { continue; }
+
+            let eye = *fs[fi].out.iter().max_by(|&&a, &&b| {
+                fs[fi].dist(&ps[a]).total_cmp(&fs[fi].dist(&ps[b]))
+            }).unwrap();
+
+            // collect the connected set of faces visible from the eye point
+            let mut vis = vec![fi];
+            let mut hzn = vec![];
+            mark[fi] = 1;
+            let mut k = 0;
+            while k < vis.len() {
+                let f = vis[k];
+                k += 1;
+                for (a, b) in fs[f].edges() {
+                    let g = e2f[&(b, a)];
+                    if mark[g] == 0 {
+                        mark[g] = if fs[g].dist(&ps[eye]) > eps { 1 } else { 2 };
+                        if mark[g] == 1 { vis.push(g); }
+                    }
+                    if mark[g] == 2 { hzn.push((a, b)); }
+                }
+            }
+
+            let mut orphans = vec![];
+            for &f in vis.iter() {
+                fs[f].alive = false;
+                for e in fs[f].edges() { e2f.remove(&e); }
+                orphans.extend(fs[f].out.drain(..).filter(|&p| p != eye));
+            }
+            for m in mark.iter_mut() { *m = 0; }
+
+            let bgn = fs.len();
+            for (a, b) in hzn {
+                let f = Face::new(&ps, [a, b, eye]);
+                for e in f.edges() { e2f.insert(e, fs.len()); }
+                fs.push(f);
+                mark.push(0);
+            }
+            let news = (bgn..fs.len()).collect::<Vec<_>>();
+            assign_outside(&ps, &mut fs, &news, &orphans, eps);
+            stack.extend(news);
+        }
+
+        // compact vertices used by the surviving faces
+        let mut old2new = vec![usize::MAX; ps.len()];
+        let mut vs = vec![];
+        let mut ts = vec![];
+        for f in fs.iter().filter(|f| f.alive) {
+            let mut t = Vec3u::ZERO;
+            for j in 0..3 {
+                let v = f.vs[j];
+                if old2new[v] == usize::MAX { old2new[v] = vs.len(); vs.push(ps[v]); }
+                t[j] = old2new[v];
+            }
+            ts.push(t);
+        }
+
+        Manifold::new_impl(vs, ts, None, None)
+    }
+}
+
+// Picks four points spanning a tetrahedron of non-negligible volume,
+// otherwise the point cloud is degenerate (collinear or coplanar).
+fn initial_simplex(ps: &[Vec3], eps: Real) -> Result<[usize; 4], String> {
+    let mut ext = [0; 6];
+    for (i, p) in ps.iter().enumerate() {
+        for a in 0..3 {
+            if p[a] < ps[ext[a * 2]][a]     { ext[a * 2] = i; }
+            if p[a] > ps[ext[a * 2 + 1]][a] { ext[a * 2 + 1] = i; }
+        }
+    }
+
+    let mut i0 = ext[0];
+    let mut i1 = ext[1];
+    for a in 0..3 {
+        let (j0, j1) = (ext[a * 2], ext[a * 2 + 1]);
+        if ps[j0].distance(ps[j1]) > ps[i0].distance(ps[i1]) { (i0, i1) = (j0, j1); }
+    }
+    if ps[i0].distance(ps[i1]) <= eps { return Err("hull points are coincident".into()); }
+
+    let dir = (ps[i1] - ps[i0]).normalize();
+    let dist_line = |p: &Vec3| (p - ps[i0]).cross(dir).length();
+    let i2 = (0..ps.len()).max_by(|&a, &b| dist_line(&ps[a]).total_cmp(&dist_line(&ps[b]))).unwrap();
+    if dist_line(&ps[i2]) <= eps { return Err("hull points are collinear".into()); }
+
+    let n = (ps[i1] - ps[i0]).cross(ps[i2] - ps[i0]).normalize();
+    let dist_plane = |p: &Vec3| (p - ps[i0]).dot(n).abs();
+    let i3 = (0..ps.len()).max_by(|&a, &b| dist_plane(&ps[a]).total_cmp(&dist_plane(&ps[b]))).unwrap();
+    if dist_plane(&ps[i3]) <= eps { return Err("hull points are coplanar".into()); }
+
+    Ok([i0, i1, i2, i3])
+}
+
+// Each point goes to the outside set of the face it is farthest above.
+// Points on or below every face are inside the hull and discarded.
+fn assign_outside(
+    ps: &[Vec3],
+    fs: &mut [Face],
+    fids: &[usize],
+    pids: &[usize],
+    eps: Real
+) {
+    for &p in pids {
+        let mut best = eps;
+        let mut face = None;
+        for &f in fids {
+            let d = fs[f].dist(&ps[p]);
+            if d > best { best = d; face = Some(f); }
+        }
+        if let Some(f) = face { fs[f].out.push(p); }
+    }
+}
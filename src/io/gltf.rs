@@ -0,0 +1,166 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use crate::{Manifold, Real, Vec3, VertexProperties};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum GltfNormals {
+    None,
+    #[default]
+    Face,   // flat shading, vertices are split per triangle
+    Smooth, // vertex normals of the manifold
+    Crease(Real), // smooth within creases of the given angle in radians, see Manifold::corner_normals
+}
+
+/// A named mesh of a glTF scene. Each attribute column is written as a float
+/// attribute named after it, upper-cased and prefixed with an underscore.
+#[derive(Clone, Copy, Debug)]
+pub struct GltfMesh<'a> {
+    pub name: &'a str,
+    pub manifold: &'a Manifold,
+    pub attributes: Option<&'a VertexProperties>,
+}
+
+fn base64(data: &[u8]) -> String {
+    const T: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut s = String::with_capacity(data.len().div_ceil(3) * 4);
+    for c in data.chunks(3) {
+        let n = (c[0] as u32) << 16 | (*c.get(1).unwrap_or(&0) as u32) << 8 | *c.get(2).unwrap_or(&0) as u32;
+        for k in 0..4 {
+            if k <= c.len() { s.push(T[(n >> (18 - 6 * k) & 63) as usize] as char); } else { s.push('='); }
+        }
+    }
+    s
+}
+
+fn json_str(s: &str) -> String {
+    let mut r = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => r.push_str("\\\""),
+            '\\' => r.push_str("\\\\"),
+            c if (c as u32) < 0x20 => { let _ = write!(r, "\\u{:04x}", c as u32); }
+            c => r.push(c),
+        }
+    }
+    r.push('"');
+    r
+}
+
+// Builds the json without the buffer uri and the binary buffer it refers to.
+fn build(meshes: &[GltfMesh], normals: GltfNormals) -> Result<(String, Vec<u8>), String> {
+    let mut bin: Vec<u8> = vec![];
+    let mut views = vec![];
+    let mut accessors = vec![];
+    let mut prims = vec![];
+
+    // appends a buffer view with its accessor and returns the accessor index
+    let mut push = |bin: &mut Vec<u8>, data: &[u8], target: u32, acc: String| {
+        views.push(format!(r#"{{"buffer":0,"byteOffset":{},"byteLength":{},"target":{target}}}"#, bin.len(), data.len()));
+        bin.extend_from_slice(data);
+        accessors.push(format!(r#"{{"bufferView":{},{acc}}}"#, views.len() - 1));
+        accessors.len() - 1
+    };
+
+    for g in meshes.iter() {
+        let m = g.manifold;
+        let empty = VertexProperties::default();
+        let props = g.attributes.unwrap_or(&empty);
+        if props.values.iter().any(|v| v.len() != m.nv) { return Err("attribute count must match the vertices".into()); }
+
+        // source vertex and normal of each buffer vertex, and the buffer vertex of each corner
+        let tris = m.triangles().into_iter().flatten().collect::<Vec<_>>();
+        let (src, nrm, idx): (Vec<usize>, Vec<Vec3>, Vec<usize>) = match normals {
+            GltfNormals::None => ((0..m.nv).collect(), vec![], tris),
+            GltfNormals::Face => (tris, (0..m.nh).map(|i| m.face_normals[i / 3]).collect(), (0..m.nh).collect()),
+            GltfNormals::Smooth => ((0..m.nv).collect(), m.vert_normals.clone(), tris),
+            GltfNormals::Crease(a) => m.crease_split(a),
+        };
+        let n = src.len();
+
+        let mut lo = [f32::MAX; 3];
+        let mut hi = [f32::MIN; 3];
+        let mut ps = vec![];
+        for &v in src.iter() {
+            let p = m.ps[v];
+            for (k, x) in [p.x as f32, p.y as f32, p.z as f32].into_iter().enumerate() {
+                lo[k] = lo[k].min(x);
+                hi[k] = hi[k].max(x);
+                ps.extend(x.to_le_bytes());
+            }
+        }
+        let mut attrs = vec![];
+        let acc = format!(r#""componentType":5126,"count":{n},"type":"VEC3","min":[{},{},{}],"max":[{},{},{}]"#, lo[0], lo[1], lo[2], hi[0], hi[1], hi[2]);
+        attrs.push(format!(r#""POSITION":{}"#, push(&mut bin, &ps, 34962, acc)));
+
+        if normals != GltfNormals::None {
+            let ns = nrm.iter().flat_map(|v| [v.x as f32, v.y as f32, v.z as f32]).flat_map(|x| x.to_le_bytes()).collect::<Vec<_>>();
+            let acc = format!(r#""componentType":5126,"count":{n},"type":"VEC3""#);
+            attrs.push(format!(r#""NORMAL":{}"#, push(&mut bin, &ns, 34962, acc)));
+        }
+
+        for (name, col) in props.names.iter().zip(props.values.iter()) {
+            let vs = src.iter().flat_map(|&v| (col[v] as f32).to_le_bytes()).collect::<Vec<_>>();
+            let acc = format!(r#""componentType":5126,"count":{n},"type":"SCALAR""#);
+            let key = json_str(&format!("_{}", name.to_uppercase()));
+            attrs.push(format!("{key}:{}", push(&mut bin, &vs, 34962, acc)));
+        }
+
+        let is = idx.iter().flat_map(|&i| (i as u32).to_le_bytes()).collect::<Vec<_>>();
+        let acc = format!(r#""componentType":5125,"count":{},"type":"SCALAR""#, m.nh);
+        let idx = push(&mut bin, &is, 34963, acc);
+        prims.push(format!(r#"{{"name":{},"primitives":[{{"attributes":{{{}}},"indices":{idx},"mode":4}}]}}"#, json_str(g.name), attrs.join(",")));
+    }
+
+    let nodes = (0..meshes.len()).map(|i| format!(r#"{{"mesh":{i},"name":{}}}"#, json_str(meshes[i].name))).collect::<Vec<_>>();
+    let mut json = String::new();
+    let _ = write!(json, r#"{{"asset":{{"version":"2.0","generator":"boolmesh"}},"scene":0,"scenes":[{{"nodes":[{}]}}],"#,
+        (0..meshes.len()).map(|i| i.to_string()).collect::<Vec<_>>().join(","));
+    let _ = write!(json, r#""nodes":[{}],"meshes":[{}],"accessors":[{}],"bufferViews":[{}],"#,
+        nodes.join(","), prims.join(","), accessors.join(","), views.join(","));
+    Ok((json, bin))
+}
+
+fn invalid(e: String) -> std::io::Error { std::io::Error::new(std::io::ErrorKind::InvalidInput, e) }
+
+impl Manifold {
+    /// Writes a self-contained glTF 2.0 json with this manifold as a single mesh.
+    pub fn write_gltf<W: Write>(&self, normals: GltfNormals, writer: &mut W) -> std::io::Result<()> {
+        let g = GltfMesh { name: "manifold", manifold: self, attributes: None };
+        Self::write_gltf_meshes(&[g], normals, writer)
+    }
+
+    /// Writes a binary glTF 2.0 with this manifold as a single mesh.
+    pub fn write_glb<W: Write>(&self, normals: GltfNormals, writer: &mut W) -> std::io::Result<()> {
+        let g = GltfMesh { name: "manifold", manifold: self, attributes: None };
+        Self::write_glb_meshes(&[g], normals, writer)
+    }
+
+    /// Writes a glTF 2.0 json with one node per mesh and the buffer embedded as a base64 data uri.
+    pub fn write_gltf_meshes<W: Write>(meshes: &[GltfMesh], normals: GltfNormals, writer: &mut W) -> std::io::Result<()> {
+        let (mut json, bin) = build(meshes, normals).map_err(invalid)?;
+        let _ = write!(json, r#""buffers":[{{"byteLength":{},"uri":"data:application/octet-stream;base64,{}"}}]}}"#, bin.len(), base64(&bin));
+        writer.write_all(json.as_bytes())
+    }
+
+    /// Writes a binary glTF 2.0 with one node per mesh, where the buffer follows the json chunk.
+    pub fn write_glb_meshes<W: Write>(meshes: &[GltfMesh], normals: GltfNormals, writer: &mut W) -> std::io::Result<()> {
+        let (mut json, mut bin) = build(meshes, normals).map_err(invalid)?;
+        let _ = write!(json, r#""buffers":[{{"byteLength":{}}}]}}"#, bin.len());
+        let mut json = json.into_bytes();
+        while json.len() % 4 != 0 { json.push(b' '); }
+        while bin.len() % 4 != 0 { bin.push(0); }
+
+        writer.write_all(&0x46546C67u32.to_le_bytes())?;
+        writer.write_all(&2u32.to_le_bytes())?;
+        writer.write_all(&(12 + 8 + json.len() as u32 + 8 + bin.len() as u32).to_le_bytes())?;
+        writer.write_all(&(json.len() as u32).to_le_bytes())?;
+        writer.write_all(&0x4E4F534Au32.to_le_bytes())?;
+        writer.write_all(&json)?;
+        writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+        writer.write_all(&0x004E4942u32.to_le_bytes())?;
+        writer.write_all(&bin)
+    }
+}
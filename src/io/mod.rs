@@ -0,0 +1,19 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+#[cfg(feature = "obj")]
+mod obj;
+mod stl;
+mod ply;
+mod gltf;
+mod off;
+mod raw;
+#[cfg(feature = "serde")]
+mod serialize;
+#[cfg(feature = "threemf")]
+mod threemf;
+
+pub use ply::{PlyFormat, VertexProperties};
+pub use gltf::{GltfNormals, GltfMesh};
+#[cfg(feature = "threemf")]
+pub use threemf::{ThreeMfUnit, ThreeMfOptions, ThreeMfObject};
@@ -0,0 +1,80 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::io::{BufRead, Write};
+use crate::{triangulate_polygons_3d, Manifold, Real, Vec3};
+
+// Flat positions and triangle indices of a model, where polygon faces are triangulated.
+fn model_buffers(m: &tobj::Mesh) -> (Vec<f64>, Vec<usize>) {
+    let pos = m.positions.iter().map(|&v| v as f64).collect::<Vec<_>>();
+    if m.face_arities.is_empty() {
+        return (pos, m.indices.iter().map(|&i| i as usize).collect());
+    }
+
+    let mut idx = vec![];
+    let mut k = 0;
+    for &n in m.face_arities.iter() {
+        let f = &m.indices[k..k + n as usize];
+        k += n as usize;
+        if n == 3 { idx.extend(f.iter().map(|&i| i as usize)); continue; }
+        let poly = f.iter().map(|&i| {
+            let i = i as usize * 3;
+            Vec3::new(pos[i] as Real, pos[i + 1] as Real, pos[i + 2] as Real)
+        }).collect::<Vec<_>>();
+        for t in triangulate_polygons_3d(&[poly], -1.) {
+            idx.extend(t.map(|j| f[j] as usize));
+        }
+    }
+    (pos, idx)
+}
+
+fn load_models<R: BufRead>(reader: &mut R) -> Result<Vec<tobj::Model>, String> {
+    let opts = tobj::LoadOptions { ignore_points: true, ignore_lines: true, ..Default::default() };
+    let (models, _) = tobj::load_obj_buf(reader, &opts, |_| Err(tobj::LoadError::OpenFileFailed))
+        .map_err(|e| e.to_string())?;
+    Ok(models)
+}
+
+impl Manifold {
+    /// Reads all objects and groups of an OBJ into a single manifold. Polygon faces are
+    /// triangulated on their plane, points and lines are ignored, and materials are not loaded.
+    pub fn from_obj<R: BufRead>(reader: &mut R) -> Result<Manifold, String> {
+        let mut pos = vec![];
+        let mut idx = vec![];
+        for m in load_models(reader)?.iter() {
+            let oft = pos.len() / 3;
+            let (p, i) = model_buffers(&m.mesh);
+            pos.extend(p);
+            idx.extend(i.iter().map(|i| i + oft));
+        }
+        Manifold::new(&pos, &idx)
+    }
+
+    /// Reads each object or group of an OBJ into its own manifold, along with its name.
+    pub fn objects_from_obj<R: BufRead>(reader: &mut R) -> Result<Vec<(String, Manifold)>, String> {
+        load_models(reader)?.iter().map(|m| {
+            let (pos, idx) = model_buffers(&m.mesh);
+            Ok((m.name.clone(), Manifold::new(&pos, &idx)?))
+        }).collect()
+    }
+
+    /// Writes the manifold as a single OBJ object of triangles.
+    pub fn write_obj<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        Self::write_obj_objects(&[("manifold", self)], writer)
+    }
+
+    /// Writes manifolds as named OBJ objects sharing one vertex list.
+    pub fn write_obj_objects<W: Write>(objects: &[(&str, &Manifold)], writer: &mut W) -> std::io::Result<()> {
+        writeln!(writer, "# boolmesh")?;
+        let mut oft = 1;
+        for (name, m) in objects.iter() {
+            writeln!(writer, "o {name}")?;
+            for p in m.ps.iter() { writeln!(writer, "v {} {} {}", p.x, p.y, p.z)?; }
+            for t in m.triangles() {
+                writeln!(writer, "f {} {} {}", t[0] + oft, t[1] + oft, t[2] + oft)?;
+            }
+            oft += m.nv;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,57 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::io::{BufRead, Write};
+use crate::{triangulate_polygons_3d, Manifold, Real, Vec3};
+
+impl Manifold {
+    /// Reads an OFF. Polygon faces are triangulated on their plane, and colors
+    /// trailing the face indices are ignored.
+    pub fn from_off<R: BufRead>(reader: &mut R) -> Result<Manifold, String> {
+        let mut lines = vec![];
+        for l in reader.lines() {
+            let l = l.map_err(|e| e.to_string())?;
+            let l = l.split('#').next().unwrap_or("").trim().to_string();
+            if !l.is_empty() { lines.push(l); }
+        }
+        let mut it = lines.iter();
+        let head = it.next().ok_or("empty off")?;
+        if !head.starts_with("OFF") { return Err("not an off file".into()); }
+
+        // the counts may follow the keyword on the same line
+        let rest = head[3..].trim();
+        let counts = if rest.is_empty() { it.next().ok_or("missing off counts")?.as_str() } else { rest };
+        let cs = counts.split_whitespace().map(|t| t.parse::<usize>()).collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())?;
+        if cs.len() < 2 { return Err("missing off counts".into()); }
+        let (nv, nf) = (cs[0], cs[1]);
+
+        let mut pos = Vec::with_capacity(nv * 3);
+        for _ in 0..nv {
+            let l = it.next().ok_or("unexpected end of off vertices")?;
+            let p = l.split_whitespace().take(3).map(|t| t.parse::<f64>()).collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())?;
+            if p.len() < 3 { return Err("off vertex needs three coordinates".into()); }
+            pos.extend(p);
+        }
+
+        let mut idx = vec![];
+        for _ in 0..nf {
+            let l = it.next().ok_or("unexpected end of off faces")?;
+            let ts = l.split_whitespace().map(|t| t.parse::<usize>()).collect::<Result<Vec<_>, _>>().map_err(|e| e.to_string())?;
+            let n = *ts.first().ok_or("empty off face")?;
+            let f = ts.get(1..n + 1).ok_or("off face is shorter than its vertex count")?;
+            if f.iter().any(|&i| i >= nv) { return Err("off face index out of range".into()); }
+            if n == 3 { idx.extend(f); continue; }
+            let poly = f.iter().map(|&i| Vec3::new(pos[i * 3] as Real, pos[i * 3 + 1] as Real, pos[i * 3 + 2] as Real)).collect::<Vec<_>>();
+            for t in triangulate_polygons_3d(&[poly], -1.) { idx.extend(t.map(|j| f[j])); }
+        }
+        Manifold::new(&pos, &idx)
+    }
+
+    /// Writes an OFF with positions printed at full precision.
+    pub fn write_off<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writeln!(writer, "OFF\n{} {} {}", self.nv, self.nf, self.nh / 2)?;
+        for p in self.ps.iter() { writeln!(writer, "{} {} {}", p.x, p.y, p.z)?; }
+        for t in self.triangles() { writeln!(writer, "3 {} {} {}", t[0], t[1], t[2])?; }
+        Ok(())
+    }
+}
@@ -0,0 +1,243 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use crate::{triangulate_polygons_3d, Manifold, Real, Vec3};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlyFormat { Ascii, BinaryLittleEndian, BinaryBigEndian }
+
+/// Vertex properties other than the positions, stored as one column of values per name
+/// and indexed by the vertices of the manifold. Booleans do not carry them over yet.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VertexProperties {
+    pub names: Vec<String>,
+    pub values: Vec<Vec<f64>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Ty { I8, U8, I16, U16, I32, U32, F32, F64 }
+
+impl Ty {
+    fn parse(s: &str) -> Result<Self, String> {
+        Ok(match s {
+            "char"   | "int8"    => Ty::I8,
+            "uchar"  | "uint8"   => Ty::U8,
+            "short"  | "int16"   => Ty::I16,
+            "ushort" | "uint16"  => Ty::U16,
+            "int"    | "int32"   => Ty::I32,
+            "uint"   | "uint32"  => Ty::U32,
+            "float"  | "float32" => Ty::F32,
+            "double" | "float64" => Ty::F64,
+            _ => return Err(format!("unknown ply type {s}")),
+        })
+    }
+
+    fn size(&self) -> usize {
+        match self {
+            Ty::I8 | Ty::U8 => 1,
+            Ty::I16 | Ty::U16 => 2,
+            Ty::I32 | Ty::U32 | Ty::F32 => 4,
+            Ty::F64 => 8,
+        }
+    }
+}
+
+struct Property {
+    name: String,
+    ty: Ty,
+    list: Option<Ty>, // type of the item count for list properties
+}
+
+struct Element {
+    name: String,
+    count: usize,
+    props: Vec<Property>,
+}
+
+enum Body<'a> {
+    Ascii(std::str::SplitWhitespace<'a>),
+    Binary { buf: &'a [u8], pos: usize, be: bool },
+}
+
+impl Body<'_> {
+    fn read(&mut self, ty: Ty) -> Result<f64, String> {
+        match self {
+            Body::Ascii(ts) => ts.next()
+                .ok_or("unexpected end of ply body")?
+                .parse::<f64>()
+                .map_err(|e| e.to_string()),
+            Body::Binary { buf, pos, be } => {
+                let n = ty.size();
+                let b = buf.get(*pos..*pos + n).ok_or("unexpected end of ply body")?;
+                *pos += n;
+                let mut a = [0u8; 8];
+                a[..n].copy_from_slice(b);
+                if *be { a[..n].reverse(); }
+                Ok(match ty {
+                    Ty::I8  => a[0] as i8 as f64,
+                    Ty::U8  => a[0] as f64,
+                    Ty::I16 => i16::from_le_bytes([a[0], a[1]]) as f64,
+                    Ty::U16 => u16::from_le_bytes([a[0], a[1]]) as f64,
+                    Ty::I32 => i32::from_le_bytes([a[0], a[1], a[2], a[3]]) as f64,
+                    Ty::U32 => u32::from_le_bytes([a[0], a[1], a[2], a[3]]) as f64,
+                    Ty::F32 => f32::from_le_bytes([a[0], a[1], a[2], a[3]]) as f64,
+                    Ty::F64 => f64::from_le_bytes(a),
+                })
+            }
+        }
+    }
+}
+
+fn parse_header(header: &str) -> Result<(PlyFormat, Vec<Element>), String> {
+    let mut format = None;
+    let mut elems: Vec<Element> = vec![];
+    for line in header.lines().skip(1) {
+        let ts = line.split_whitespace().collect::<Vec<_>>();
+        match ts.as_slice() {
+            ["format", f, ..] => format = Some(match *f {
+                "ascii" => PlyFormat::Ascii,
+                "binary_little_endian" => PlyFormat::BinaryLittleEndian,
+                "binary_big_endian" => PlyFormat::BinaryBigEndian,
+                _ => return Err(format!("unknown ply format {f}")),
+            }),
+            ["element", name, count] => elems.push(Element {
+                name: name.to_string(),
+                count: count.parse().map_err(|_| "invalid element count")?,
+                props: vec![],
+            }),
+            ["property", "list", c, t, name] => elems.last_mut().ok_or("property before element")?
+                .props.push(Property { name: name.to_string(), ty: Ty::parse(t)?, list: Some(Ty::parse(c)?) }),
+            ["property", t, name] => elems.last_mut().ok_or("property before element")?
+                .props.push(Property { name: name.to_string(), ty: Ty::parse(t)?, list: None }),
+            _ => {} // comments, obj_info and end_header
+        }
+    }
+    Ok((format.ok_or("missing ply format")?, elems))
+}
+
+impl Manifold {
+    /// Reads an ASCII or binary PLY with a `vertex` and a `face` element. Polygon faces are
+    /// triangulated on their plane and other elements are skipped. The vertex properties
+    /// besides x, y and z are returned alongside, indexed by the vertices of the manifold.
+    pub fn from_ply<R: Read>(reader: &mut R) -> Result<(Manifold, VertexProperties), String> {
+        let mut buf = vec![];
+        reader.read_to_end(&mut buf).map_err(|e| e.to_string())?;
+        if !buf.starts_with(b"ply") { return Err("not a ply file".into()); }
+        let end = buf.windows(10).position(|w| w == b"end_header").ok_or("missing end_header")?;
+        let bgn = end + buf[end..].iter().position(|&b| b == b'\n').ok_or("missing end_header")? + 1;
+        let header = std::str::from_utf8(&buf[..bgn]).map_err(|e| e.to_string())?;
+        let (format, elems) = parse_header(header)?;
+
+        let mut body = match format {
+            PlyFormat::Ascii => Body::Ascii(std::str::from_utf8(&buf[bgn..]).map_err(|e| e.to_string())?.split_whitespace()),
+            PlyFormat::BinaryLittleEndian => Body::Binary { buf: &buf, pos: bgn, be: false },
+            PlyFormat::BinaryBigEndian => Body::Binary { buf: &buf, pos: bgn, be: true },
+        };
+
+        let mut cols: Vec<Vec<f64>> = vec![];
+        let mut names = vec![];
+        let mut faces = vec![];
+        for e in elems.iter() {
+            if e.name == "vertex" {
+                names = e.props.iter().map(|p| p.name.clone()).collect();
+                cols = vec![Vec::with_capacity(e.count); e.props.len()];
+            }
+            for _ in 0..e.count {
+                for (k, p) in e.props.iter().enumerate() {
+                    match p.list {
+                        None => {
+                            let v = body.read(p.ty)?;
+                            if e.name == "vertex" { cols[k].push(v); }
+                        }
+                        Some(c) => {
+                            let n = body.read(c)? as usize;
+                            let vs = (0..n).map(|_| body.read(p.ty)).collect::<Result<Vec<_>, _>>()?;
+                            if e.name == "face" && (p.name == "vertex_indices" || p.name == "vertex_index") {
+                                faces.push(vs.iter().map(|&v| v as usize).collect::<Vec<_>>());
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let col = |n: &str| names.iter().position(|s| s == n).ok_or(format!("missing vertex property {n}"));
+        let (x, y, z) = (col("x")?, col("y")?, col("z")?);
+        let nv = cols.first().map_or(0, |c| c.len());
+        let pos = (0..nv).flat_map(|i| [cols[x][i], cols[y][i], cols[z][i]]).collect::<Vec<_>>();
+
+        let mut idx = vec![];
+        for f in faces.iter() {
+            if f.iter().any(|&i| i >= nv) { return Err("face index out of range".into()); }
+            if f.len() == 3 { idx.extend(f.iter()); continue; }
+            let poly = f.iter().map(|&i| Vec3::new(pos[i * 3] as Real, pos[i * 3 + 1] as Real, pos[i * 3 + 2] as Real)).collect::<Vec<_>>();
+            for t in triangulate_polygons_3d(&[poly], -1.) { idx.extend(t.map(|j| f[j])); }
+        }
+        let m = Manifold::new(&pos, &idx)?;
+
+        // follows the welding of Manifold::new, which keeps the first of bit-identical positions
+        let mut seen = HashMap::new();
+        let firsts = (0..nv).filter(|&i| {
+            let p = [x, y, z].map(|c| (cols[c][i] as Real).to_bits());
+            seen.insert(p, i).is_none()
+        }).collect::<Vec<_>>();
+        let mut props = VertexProperties::default();
+        for (k, n) in names.iter().enumerate() {
+            if k == x || k == y || k == z { continue; }
+            props.names.push(n.clone());
+            props.values.push(firsts.iter().map(|&i| cols[k][i]).collect());
+        }
+        Ok((m, props))
+    }
+
+    /// Writes a PLY with positions at the precision of `Real`, the given vertex properties
+    /// as doubles, and triangle faces.
+    pub fn write_ply<W: Write>(
+        &self,
+        writer: &mut W,
+        props: Option<&VertexProperties>,
+        format: PlyFormat,
+    ) -> std::io::Result<()> {
+        let empty = VertexProperties::default();
+        let props = props.unwrap_or(&empty);
+        if props.values.iter().any(|v| v.len() != self.nv) {
+            return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, "property count must match the vertices"));
+        }
+
+        let f = match format {
+            PlyFormat::Ascii => "ascii",
+            PlyFormat::BinaryLittleEndian => "binary_little_endian",
+            PlyFormat::BinaryBigEndian => "binary_big_endian",
+        };
+        writeln!(writer, "ply\nformat {f} 1.0\ncomment boolmesh")?;
+        writeln!(writer, "element vertex {}", self.nv)?;
+        let real = if size_of::<Real>() == 8 { "double" } else { "float" };
+        for n in ["x", "y", "z"] { writeln!(writer, "property {real} {n}")?; }
+        for n in props.names.iter() { writeln!(writer, "property double {n}")?; }
+        writeln!(writer, "element face {}\nproperty list uchar int vertex_indices\nend_header", self.nf)?;
+
+        let be = format == PlyFormat::BinaryBigEndian;
+        for (i, p) in self.ps.iter().enumerate() {
+            let row = props.values.iter().map(|c| c[i]);
+            if format == PlyFormat::Ascii {
+                let ts = [p.x, p.y, p.z].map(|v| v.to_string()).into_iter().chain(row.map(|v| v.to_string()));
+                writeln!(writer, "{}", ts.collect::<Vec<_>>().join(" "))?;
+            } else {
+                for v in [p.x, p.y, p.z] { writer.write_all(&if be { v.to_be_bytes() } else { v.to_le_bytes() })?; }
+                for v in row { writer.write_all(&if be { v.to_be_bytes() } else { v.to_le_bytes() })?; }
+            }
+        }
+        for t in self.triangles() {
+            let t = t.map(|i| i as i32);
+            if format == PlyFormat::Ascii {
+                writeln!(writer, "3 {} {} {}", t[0], t[1], t[2])?;
+            } else {
+                writer.write_all(&[3])?;
+                for i in t { writer.write_all(&if be { i.to_be_bytes() } else { i.to_le_bytes() })?; }
+            }
+        }
+        Ok(())
+    }
+}
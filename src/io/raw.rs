@@ -0,0 +1,71 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::io::{Read, Write};
+use crate::{Manifold, Real, Vec3, Vec3u};
+
+const MAGIC: &[u8; 4] = b"BMSH";
+const VERSION: u32 = 1;
+
+// Little endian layout of version 1:
+//   magic, version: u32, width of Real in bytes: u32, nv: u64, nf: u64,
+//   eps and tol as Real, nv * 3 positions as Real, nf * 3 indices as u64.
+impl Manifold {
+    /// Writes an exact dump of the positions, triangles, eps and tol, which `load_raw`
+    /// reloads bit-identically when built with the same `Real`.
+    pub fn save_raw<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(MAGIC)?;
+        writer.write_all(&VERSION.to_le_bytes())?;
+        writer.write_all(&(size_of::<Real>() as u32).to_le_bytes())?;
+        writer.write_all(&(self.nv as u64).to_le_bytes())?;
+        writer.write_all(&(self.nf as u64).to_le_bytes())?;
+        writer.write_all(&self.eps.to_le_bytes())?;
+        writer.write_all(&self.tol.to_le_bytes())?;
+        for p in self.ps.iter() {
+            for x in [p.x, p.y, p.z] { writer.write_all(&x.to_le_bytes())?; }
+        }
+        for v in self.triangles().into_iter().flatten() { writer.write_all(&(v as u64).to_le_bytes())?; }
+        Ok(())
+    }
+
+    /// Reads a dump written by `save_raw`. Dumps of f32 builds load into f64 builds
+    /// exactly, while the other way round is rejected.
+    pub fn load_raw<R: Read>(reader: &mut R) -> Result<Manifold, String> {
+        let mut buf = vec![];
+        reader.read_to_end(&mut buf).map_err(|e| e.to_string())?;
+        if buf.len() < 28 || &buf[0..4] != MAGIC { return Err("not a raw manifold dump".into()); }
+
+        let mut o = 4;
+        let mut take = |n: usize| -> Result<&[u8], String> {
+            let b = buf.get(o..o + n).ok_or("unexpected end of raw dump")?;
+            o += n;
+            Ok(b)
+        };
+        let u32_ = |b: &[u8]| u32::from_le_bytes(b.try_into().unwrap());
+        let u64_ = |b: &[u8]| u64::from_le_bytes(b.try_into().unwrap()) as usize;
+
+        let ver = u32_(take(4)?);
+        if ver != VERSION { return Err(format!("unsupported raw dump version {ver}")); }
+        let w = u32_(take(4)?) as usize;
+        if w != 4 && w != 8 { return Err(format!("invalid real width {w}")); }
+        if w > size_of::<Real>() { return Err("raw dump has f64 positions but Real is f32".into()); }
+        let nv = u64_(take(8)?);
+        let nf = u64_(take(8)?);
+
+        let mut real = || -> Result<Real, String> {
+            let b = take(w)?;
+            Ok(if w == 4 { f32::from_le_bytes(b.try_into().unwrap()) as Real } else { f64::from_le_bytes(b.try_into().unwrap()) as Real })
+        };
+        let eps = real()?;
+        let tol = real()?;
+        let ps = (0..nv).map(|_| Ok(Vec3::new(real()?, real()?, real()?))).collect::<Result<Vec<_>, String>>()?;
+
+        let mut idx = vec![];
+        for _ in 0..nf {
+            let t = Vec3u::new(u64_(take(8)?), u64_(take(8)?), u64_(take(8)?));
+            if t.max_element() >= nv { return Err("raw dump index out of range".into()); }
+            idx.push(t);
+        }
+        Manifold::new_impl(ps, idx, Some(eps), Some(tol))
+    }
+}
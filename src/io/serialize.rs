@@ -0,0 +1,41 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use crate::{Manifold, Real, Vec3, Vec3u};
+
+// Only the defining data is stored, the collider, normals and coplanar groups are rebuilt on load.
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Manifold")]
+struct Data {
+    ps: Vec<[Real; 3]>,
+    tris: Vec<[usize; 3]>,
+    eps: Real,
+    tol: Real,
+}
+
+impl Serialize for Manifold {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Data {
+            ps: self.ps.iter().map(|p| [p.x, p.y, p.z]).collect(),
+            tris: self.triangles(),
+            eps: self.eps,
+            tol: self.tol,
+        }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Manifold {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let d = Data::deserialize(deserializer)?;
+        if d.tris.iter().flatten().any(|&i| i >= d.ps.len()) {
+            return Err(serde::de::Error::custom("triangle index out of range"));
+        }
+        Manifold::new_impl(
+            d.ps.iter().map(|p| Vec3::new(p[0], p[1], p[2])).collect(),
+            d.tris.iter().map(|&t| Vec3u::from_array(t)).collect(),
+            Some(d.eps),
+            Some(d.tol),
+        ).map_err(serde::de::Error::custom)
+    }
+}
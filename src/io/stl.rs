@@ -0,0 +1,126 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use crate::Manifold;
+
+// Weld tolerance relative to the largest extent of the bounding box.
+const K_WELD: f64 = 1e-6;
+
+// Welds points closer than `tol` to the first of them, looking up the neighboring cells of a grid.
+// Returns the welded positions and the map from every input point to its welded one.
+fn weld(ps: &[[f64; 3]], tol: f64) -> (Vec<f64>, Vec<usize>) {
+    let key = |p: &[f64; 3]| p.map(|v| (v / tol).floor() as i64);
+    let mut grid: HashMap<[i64; 3], Vec<usize>> = HashMap::new();
+    let mut reps: Vec<[f64; 3]> = vec![];
+    let mut map = Vec::with_capacity(ps.len());
+
+    for p in ps.iter() {
+        let k = key(p);
+        let mut found = None;
+        'search: for dx in -1..=1 {
+            for dy in -1..=1 {
+                for dz in -1..=1 {
+                    let Some(cell) = grid.get(&[k[0] + dx, k[1] + dy, k[2] + dz]) else { continue; };
+                    for &r in cell.iter() {
+                        let d = (0..3).map(|i| (reps[r][i] - p[i]).powi(2)).sum::<f64>();
+                        if d <= tol * tol { found = Some(r); break 'search; }
+                    }
+                }
+            }
+        }
+        map.push(found.unwrap_or_else(|| {
+            grid.entry(k).or_default().push(reps.len());
+            reps.push(*p);
+            reps.len() - 1
+        }));
+    }
+    (reps.concat(), map)
+}
+
+fn parse_binary(buf: &[u8]) -> Vec<[f64; 3]> {
+    let n = u32::from_le_bytes(buf[80..84].try_into().unwrap()) as usize;
+    let f = |o: usize| f32::from_le_bytes(buf[o..o + 4].try_into().unwrap()) as f64;
+    let mut ps = Vec::with_capacity(n * 3);
+    for t in 0..n {
+        let o = 84 + t * 50 + 12; // skips the facet normal
+        for v in 0..3 { ps.push([0, 1, 2].map(|i| f(o + v * 12 + i * 4))); }
+    }
+    ps
+}
+
+fn parse_ascii(buf: &[u8]) -> Result<Vec<[f64; 3]>, String> {
+    let s = std::str::from_utf8(buf).map_err(|e| e.to_string())?;
+    let mut ts = s.split_whitespace();
+    let mut ps = vec![];
+    while let Some(t) = ts.next() {
+        if t != "vertex" { continue; }
+        let mut p = [0.; 3];
+        for v in p.iter_mut() {
+            *v = ts.next().ok_or("truncated vertex")?.parse::<f64>().map_err(|e| e.to_string())?;
+        }
+        ps.push(p);
+    }
+    if ps.len() % 3 != 0 { return Err("facet must have three vertices".into()); }
+    Ok(ps)
+}
+
+impl Manifold {
+    /// Reads an ASCII or binary STL. A file whose size matches the triangle count in the header
+    /// is read as binary even if it starts with `solid`. As STL repeats the vertices of every
+    /// facet, the vertices are welded within a tolerance relative to the bounding box.
+    pub fn from_stl<R: Read>(reader: &mut R) -> Result<Manifold, String> {
+        let mut buf = vec![];
+        reader.read_to_end(&mut buf).map_err(|e| e.to_string())?;
+
+        let binary = buf.len() >= 84 &&
+            buf.len() == 84 + 50 * u32::from_le_bytes(buf[80..84].try_into().unwrap()) as usize;
+        let ps = if binary { parse_binary(&buf) }
+            else if buf.trim_ascii_start().starts_with(b"solid") { parse_ascii(&buf)? }
+            else { return Err("unrecognized stl".into()); };
+
+        let mut lo = [f64::MAX; 3];
+        let mut hi = [f64::MIN; 3];
+        for p in ps.iter() {
+            for i in 0..3 { lo[i] = lo[i].min(p[i]); hi[i] = hi[i].max(p[i]); }
+        }
+        let scale = (0..3).map(|i| hi[i] - lo[i]).fold(0., f64::max);
+        let (pos, map) = weld(&ps, (K_WELD * scale).max(f64::MIN_POSITIVE));
+        Manifold::new(&pos, &map)
+    }
+
+    /// Writes a binary STL with the face normals.
+    pub fn write_stl_binary<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut header = [0u8; 80];
+        header[..8].copy_from_slice(b"boolmesh");
+        writer.write_all(&header)?;
+        writer.write_all(&(self.nf as u32).to_le_bytes())?;
+        for (f, t) in self.triangles().iter().enumerate() {
+            let n = self.face_normals[f];
+            let vs = [n, self.ps[t[0]], self.ps[t[1]], self.ps[t[2]]];
+            for v in vs.iter() {
+                for x in [v.x, v.y, v.z] { writer.write_all(&(x as f32).to_le_bytes())?; }
+            }
+            writer.write_all(&[0, 0])?;
+        }
+        Ok(())
+    }
+
+    /// Writes an ASCII STL with the face normals.
+    pub fn write_stl_ascii<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writeln!(writer, "solid boolmesh")?;
+        for (f, t) in self.triangles().iter().enumerate() {
+            let n = self.face_normals[f];
+            writeln!(writer, "  facet normal {} {} {}", n.x, n.y, n.z)?;
+            writeln!(writer, "    outer loop")?;
+            for &v in t.iter() {
+                let p = self.ps[v];
+                writeln!(writer, "      vertex {} {} {}", p.x, p.y, p.z)?;
+            }
+            writeln!(writer, "    endloop")?;
+            writeln!(writer, "  endfacet")?;
+        }
+        writeln!(writer, "endsolid boolmesh")
+    }
+}
@@ -0,0 +1,160 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::fmt::Write as _;
+use std::io::Write;
+use crate::Manifold;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ThreeMfUnit { Micron, #[default] Millimeter, Centimeter, Inch, Foot, Meter }
+
+#[derive(Clone, Debug, Default)]
+pub struct ThreeMfOptions {
+    pub unit: ThreeMfUnit,
+    pub colors: Vec<[u8; 4]>, // sRGBA palette indexed by the per-triangle color ids
+}
+
+/// A named mesh of a 3MF package. `color_ids` assigns a palette entry to every triangle,
+/// e.g. from `Manifold::source_indices` to color the faces of a boolean result by operand.
+#[derive(Clone, Copy, Debug)]
+pub struct ThreeMfObject<'a> {
+    pub name: &'a str,
+    pub manifold: &'a Manifold,
+    pub color_ids: Option<&'a [usize]>,
+}
+
+const CONTENT_TYPES: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Types xmlns="http://schemas.openxmlformats.org/package/2006/content-types"><Default Extension="rels" ContentType="application/vnd.openxmlformats-package.relationships+xml"/><Default Extension="model" ContentType="application/vnd.ms-package.3dmanufacturing-3dmodel+xml"/></Types>"#;
+
+const RELS: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<Relationships xmlns="http://schemas.openxmlformats.org/package/2006/relationships"><Relationship Target="/3D/3dmodel.model" Id="rel0" Type="http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel"/></Relationships>"#;
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut c = !0u32;
+    for &b in data {
+        c ^= b as u32;
+        for _ in 0..8 { c = if c & 1 != 0 { (c >> 1) ^ 0xEDB88320 } else { c >> 1 }; }
+    }
+    !c
+}
+
+// Writes the entries as an uncompressed zip archive.
+fn write_zip<W: Write>(entries: &[(&str, &[u8])], w: &mut W) -> std::io::Result<()> {
+    let mut cd = vec![];
+    let mut oft = 0u32;
+    for (name, data) in entries.iter() {
+        let (crc, len, nlen) = (crc32(data), data.len() as u32, name.len() as u16);
+        // version, flags, method, time, date (1980-01-01), crc, sizes, name and extra length
+        let mut common = vec![];
+        common.extend(20u16.to_le_bytes());
+        common.extend(0u16.to_le_bytes());
+        common.extend(0u16.to_le_bytes());
+        common.extend(0u16.to_le_bytes());
+        common.extend(0x21u16.to_le_bytes());
+        common.extend(crc.to_le_bytes());
+        common.extend(len.to_le_bytes());
+        common.extend(len.to_le_bytes());
+        common.extend(nlen.to_le_bytes());
+        common.extend(0u16.to_le_bytes());
+
+        w.write_all(&0x04034b50u32.to_le_bytes())?;
+        w.write_all(&common)?;
+        w.write_all(name.as_bytes())?;
+        w.write_all(data)?;
+
+        cd.extend(0x02014b50u32.to_le_bytes());
+        cd.extend(20u16.to_le_bytes());
+        cd.extend(common);
+        cd.extend([0u8; 10]); // comment length, disk, internal and external attributes
+        cd.extend(oft.to_le_bytes());
+        cd.extend(name.as_bytes());
+        oft += 30 + nlen as u32 + len;
+    }
+    w.write_all(&cd)?;
+    w.write_all(&0x06054b50u32.to_le_bytes())?;
+    w.write_all(&[0u8; 4])?;
+    w.write_all(&(entries.len() as u16).to_le_bytes())?;
+    w.write_all(&(entries.len() as u16).to_le_bytes())?;
+    w.write_all(&(cd.len() as u32).to_le_bytes())?;
+    w.write_all(&oft.to_le_bytes())?;
+    w.write_all(&0u16.to_le_bytes())
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
+
+fn model_xml(objects: &[ThreeMfObject], options: &ThreeMfOptions) -> Result<String, String> {
+    let unit = match options.unit {
+        ThreeMfUnit::Micron => "micron",
+        ThreeMfUnit::Millimeter => "millimeter",
+        ThreeMfUnit::Centimeter => "centimeter",
+        ThreeMfUnit::Inch => "inch",
+        ThreeMfUnit::Foot => "foot",
+        ThreeMfUnit::Meter => "meter",
+    };
+    let colored = !options.colors.is_empty();
+    let mut s = String::new();
+    let _ = writeln!(s, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    let _ = writeln!(s, r#"<model unit="{unit}" xml:lang="en-US" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02" xmlns:m="http://schemas.microsoft.com/3dmanufacturing/material/2015/02">"#);
+    let _ = writeln!(s, "<resources>");
+    if colored {
+        let _ = write!(s, r#"<m:colorgroup id="1">"#);
+        for c in options.colors.iter() {
+            let _ = write!(s, r##"<m:color color="#{:02X}{:02X}{:02X}{:02X}"/>"##, c[0], c[1], c[2], c[3]);
+        }
+        let _ = writeln!(s, "</m:colorgroup>");
+    }
+
+    // the color group takes id 1 and objects follow from 2
+    for (i, o) in objects.iter().enumerate() {
+        let m = o.manifold;
+        let ids = match (colored, o.color_ids) {
+            (true, Some(ids)) => {
+                if ids.len() != m.nf { return Err("color ids must match the triangles".into()); }
+                if ids.iter().any(|&k| k >= options.colors.len()) { return Err("color id out of range".into()); }
+                Some(ids)
+            }
+            _ => None,
+        };
+        let pid = if ids.is_some() { r#" pid="1" pindex="0""# } else { "" };
+        let _ = writeln!(s, r#"<object id="{}" name="{}" type="model"{pid}><mesh><vertices>"#, i + 2, escape(o.name));
+        for p in m.ps.iter() { let _ = writeln!(s, r#"<vertex x="{}" y="{}" z="{}"/>"#, p.x, p.y, p.z); }
+        let _ = writeln!(s, "</vertices><triangles>");
+        for (f, t) in m.triangles().iter().enumerate() {
+            let _ = write!(s, r#"<triangle v1="{}" v2="{}" v3="{}""#, t[0], t[1], t[2]);
+            if let Some(ids) = ids { let _ = write!(s, r#" pid="1" p1="{}""#, ids[f]); }
+            let _ = writeln!(s, "/>");
+        }
+        let _ = writeln!(s, "</triangles></mesh></object>");
+    }
+
+    let _ = writeln!(s, "</resources>\n<build>");
+    for i in 0..objects.len() { let _ = writeln!(s, r#"<item objectid="{}"/>"#, i + 2); }
+    let _ = write!(s, "</build>\n</model>");
+    Ok(s)
+}
+
+impl Manifold {
+    /// Writes a 3MF package with this manifold as a single object in millimeters.
+    pub fn write_3mf<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let o = ThreeMfObject { name: "manifold", manifold: self, color_ids: None };
+        Self::write_3mf_objects(&[o], &ThreeMfOptions::default(), writer)
+    }
+
+    /// Writes a 3MF package with one build item per object. Triangles are colored from
+    /// the palette of the options when the object has color ids.
+    pub fn write_3mf_objects<W: Write>(
+        objects: &[ThreeMfObject],
+        options: &ThreeMfOptions,
+        writer: &mut W,
+    ) -> std::io::Result<()> {
+        let model = model_xml(objects, options)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidInput, e))?;
+        write_zip(&[
+            ("[Content_Types].xml", CONTENT_TYPES.as_bytes()),
+            ("_rels/.rels", RELS.as_bytes()),
+            ("3D/3dmodel.model", model.as_bytes()),
+        ], writer)
+    }
+}
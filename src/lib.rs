@@ -11,6 +11,14 @@ mod simplification;
 mod common;
 mod boolean03;
 mod boolean45;
+mod hull;
+mod extrusion;
+mod cross_section;
+mod io;
+mod minkowski;
+mod offset;
+#[cfg(feature = "primitives")]
+mod primitives;
 mod tests;
 
 use crate::boolean03::boolean03;
@@ -20,12 +28,32 @@ use crate::triangulation::triangulate;
 use crate::common::*;
 use crate::manifold::*;
 
-pub use crate::common::{Real, Vec2, Vec3, Vec4, Mat3, K_PRECISION};
+pub use crate::common::{Real, Vec2, Vec3, Vec4, Mat3, K_PRECISION, Half, next_of};
+pub use crate::manifold::FaceOrigin;
+pub use crate::manifold::buffers::MeshBuffers;
+pub use crate::manifold::topology::{Topology, Star};
+pub use crate::extrusion::sweep::SweepOptions;
+pub use crate::extrusion::loft::Plane;
+pub use crate::cross_section::CrossSection;
+pub use crate::io::{PlyFormat, VertexProperties, GltfNormals, GltfMesh};
+#[cfg(feature = "threemf")]
+pub use crate::io::{ThreeMfUnit, ThreeMfOptions, ThreeMfObject};
+pub use crate::triangulation::{triangulate_polygons, triangulate_polygons_3d, dump_polygons, load_polygons};
 
 pub mod prelude {
-    pub use crate::common::OpType;
-    pub use crate::manifold::Manifold;
+    pub use crate::common::{OpType, BooleanOptions};
+    pub use crate::manifold::{Manifold, FaceOrigin};
+    pub use crate::manifold::buffers::MeshBuffers;
+    pub use crate::extrusion::sweep::SweepOptions;
+    pub use crate::extrusion::loft::Plane;
+    pub use crate::cross_section::CrossSection;
+    pub use crate::io::{PlyFormat, VertexProperties, GltfNormals, GltfMesh};
+    #[cfg(feature = "threemf")]
+    pub use crate::io::{ThreeMfUnit, ThreeMfOptions, ThreeMfObject};
     pub use crate::compute_boolean;
+    pub use crate::compute_boolean_with_options;
+    pub use crate::compute_boolean_all;
+    pub use crate::minkowski::minkowski_sum;
 }
 
 pub fn compute_boolean(
@@ -33,12 +61,31 @@ pub fn compute_boolean(
     mq: &Manifold,
     op: OpType,
 ) -> Result<Manifold, String> {
+    compute_boolean_with_options(mp, mq, op, &BooleanOptions::default())
+}
+
+pub fn compute_boolean_with_options(
+    mp: &Manifold,
+    mq: &Manifold,
+    op: OpType,
+    options: &BooleanOptions,
+) -> Result<Manifold, String> {
+    compute_boolean_opt(mp, mq, op, options)?.ok_or_else(|| "the boolean result is empty".into())
+}
+
+// Same as compute_boolean_with_options, but an empty result is None rather than an error.
+pub(crate) fn compute_boolean_opt(
+    mp: &Manifold,
+    mq: &Manifold,
+    op: OpType,
+    options: &BooleanOptions,
+) -> Result<Option<Manifold>, String> {
     let eps = mp.eps.max(mq.eps);
     let tol = mp.tol.max(mq.tol);
 
     let     b03 = boolean03(mp, mq, &op);
     let mut b45 = boolean45(mp, mq, &b03, &op);
-    let mut trg = triangulate(mp, mq, &b45, eps)?;
+    let mut trg = triangulate(mp, mq, &b45, eps, options.delaunay)?;
 
     simplify_topology(
         &mut trg.hs,
@@ -50,20 +97,58 @@ pub fn compute_boolean(
         eps
     );
 
+    // source faces of the triangles surviving the simplification
+    let origins = trg.hs
+        .chunks(3)
+        .zip(trg.rs.iter())
+        .filter(|(hs, _)| hs[0].pair().is_some())
+        .map(|(_, r)| if r.mid == 0 { mp.origins[r.fid] } else { mq.origins[r.fid] })
+        .collect();
+
     cleanup_unused_verts(
         &mut b45.ps,
         &mut trg.hs
     );
 
-    Manifold::new_impl(
+    if b45.ps.is_empty() || trg.hs.is_empty() { return Ok(None); }
+
+    Manifold::new_with_origins(
         b45.ps,
         trg.hs
             .chunks(3)
             .map(|hs| Vec3u::new(hs[0].tail, hs[1].tail, hs[2].tail))
             .collect(),
+        Some(origins),
         Some(eps),
         Some(tol)
-    )
+    ).map(Some)
+}
+
+/// Applies `op` over a batch of manifolds. Add and Intersect are reduced as a balanced tree
+/// so the intermediate meshes stay small, and Subtract removes the union of the rest from the first.
+pub fn compute_boolean_all(
+    ms: &[Manifold],
+    op: OpType,
+) -> Result<Manifold, String> {
+    match ms.len() {
+        0 => Err("no manifold is given".into()),
+        1 => Ok(ms[0].clone()),
+        _ if op == OpType::Subtract => {
+            let rest = compute_boolean_all(&ms[1..], OpType::Add)?;
+            compute_boolean(&ms[0], &rest, op)
+        }
+        n => {
+            let (l, r) = ms.split_at(n / 2);
+
+            #[cfg(feature = "rayon")]
+            let (ml, mr) = rayon::join(|| compute_boolean_all(l, op), || compute_boolean_all(r, op));
+
+            #[cfg(not(feature = "rayon"))]
+            let (ml, mr) = (compute_boolean_all(l, op), compute_boolean_all(r, op));
+
+            compute_boolean(&ml?, &mr?, op)
+        }
+    }
 }
 
 
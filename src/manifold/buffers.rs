@@ -0,0 +1,104 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use crate::{Manifold, Real, Vec3, next_of};
+
+/// Flat vertex and index arrays ready for upload to a renderer.
+#[derive(Clone, Debug, Default)]
+pub struct MeshBuffers<I> {
+    pub positions: Vec<Real>, // xyz per vertex
+    pub normals: Vec<Real>,   // xyz per vertex
+    pub indices: Vec<I>,      // three per triangle, ccw
+    pub source: Vec<usize>,   // manifold vertex each buffer vertex was copied from
+}
+
+impl Manifold {
+    /// Vertex indices of each triangle, ccw seen from outside.
+    pub fn triangles(&self) -> Vec<[usize; 3]> {
+        self.hs.chunks(3).map(|hs| [hs[0].tail, hs[1].tail, hs[2].tail]).collect()
+    }
+
+    pub fn positions_f32(&self) -> Vec<[f32; 3]> {
+        self.ps.iter().map(|p| [p.x as f32, p.y as f32, p.z as f32]).collect()
+    }
+
+    pub fn positions_f64(&self) -> Vec<[f64; 3]> {
+        self.ps.iter().map(|p| [p.x, p.y, p.z].map(f64::from)).collect()
+    }
+
+    /// Indexed buffers of the mesh. Without `sharp_angle` the vertices are shared as in the manifold
+    /// and carry the vertex normals. Otherwise vertices are split along creases as in `corner_normals`.
+    /// Fails if a vertex index does not fit in `I`.
+    pub fn to_buffers<I: TryFrom<usize>>(&self, sharp_angle: Option<Real>) -> Result<MeshBuffers<I>, String> {
+        let (source, normals, corners) = match sharp_angle {
+            None => ((0..self.nv).collect(), self.vert_normals.clone(), self.hs.iter().map(|h| h.tail).collect()),
+            Some(a) => self.crease_split(a),
+        };
+        let indices = corners.into_iter()
+            .map(|i| I::try_from(i).map_err(|_| "vertex index does not fit in the index type".to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(MeshBuffers {
+            positions: source.iter().flat_map(|&v| self.ps[v].to_array()).collect(),
+            normals: normals.iter().flat_map(|n| n.to_array()).collect(),
+            indices,
+            source,
+        })
+    }
+
+    /// Normal of each corner, indexed like the halfedges so that corner `3 * f + k` belongs to
+    /// `triangles()[f][k]`. The corners around a vertex are grouped into fans bounded by creases,
+    /// edges whose dihedral angle exceeds `crease_angle` in radians, and share the normal of their fan.
+    /// Coplanar faces are never split, and each planar region of a fan contributes its plane normal
+    /// weighted by its angle at the vertex, so the tessellation of flat regions does not bias the result.
+    pub fn corner_normals(&self, crease_angle: Real) -> Vec<Vec3> {
+        let (_, nrm, fan) = self.crease_split(crease_angle);
+        fan.iter().map(|&i| nrm[i]).collect()
+    }
+
+    // Gives each fan bounded by creases its own vertex. Returns the source vertex
+    // and normal of each fan, and the fan of each corner.
+    pub(crate) fn crease_split(&self, angle: Real) -> (Vec<usize>, Vec<Vec3>, Vec<usize>) {
+        let cos = angle.cos();
+        // the corner of halfedge h sits at its tail, and the next corner around the tail lies across h
+        let rot = |h: usize| next_of(self.hs[h].pair);
+        let plane = |h: usize| self.coplanar[h / 3] as usize;
+        let sharp = |h: usize| {
+            let (a, b) = (plane(h), plane(self.hs[h].pair));
+            a != b && self.face_normals[a].dot(self.face_normals[b]) < cos
+        };
+
+        let mut fan = vec![usize::MAX; self.nh];
+        let mut src = vec![];
+        let mut nrm = vec![];
+        for h in 0..self.nh {
+            if fan[h] != usize::MAX { continue; }
+            let mut ring = vec![h];
+            let mut c = rot(h);
+            while c != h { ring.push(c); c = rot(c); }
+
+            let n = ring.len();
+            let s = ring.iter().position(|&c| sharp(c)).map_or(0, |k| k + 1);
+            for k in 0..n {
+                let c = ring[(s + k) % n];
+                if k == 0 || sharp(ring[(s + k + n - 1) % n]) {
+                    src.push(self.hs[c].tail);
+                    nrm.push(Vec3::ZERO);
+                }
+                fan[c] = src.len() - 1;
+                *nrm.last_mut().unwrap() += self.face_normals[plane(c)] * self.corner_angle(c);
+            }
+        }
+        for (i, n) in nrm.iter_mut().enumerate() {
+            let m = n.normalize_or_zero();
+            *n = if m == Vec3::ZERO { self.vert_normals[src[i]] } else { m };
+        }
+        (src, nrm, fan)
+    }
+
+    fn corner_angle(&self, h: usize) -> Real {
+        let p = self.ps[self.hs[h].tail];
+        let a = self.ps[self.hs[h].head] - p;
+        let b = self.ps[self.hs[next_of(next_of(h))].tail] - p;
+        if a.length_squared() * b.length_squared() == 0. { 0. } else { a.angle_between(b) }
+    }
+}
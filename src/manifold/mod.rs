@@ -4,9 +4,12 @@
 pub mod hmesh;
 pub mod bounds;
 pub mod collider;
+pub mod buffers;
+pub mod topology;
 
 use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::sync::atomic::{self, AtomicUsize};
 use bounds::BBox;
 use crate::collider::{morton_code, MortonCollider, K_NO_CODE};
 use crate::{Real, Half, Vec3, Vec3u, K_PRECISION, next_of, Mat3};
@@ -27,11 +30,22 @@ pub struct Manifold {
     pub bounding_box: BBox,       //
     pub face_normals: Vec<Vec3>,  //
     pub vert_normals: Vec<Vec3>,  //
-    pub original_idx: Vec<usize>, //
+    pub(crate) id: usize,         // unique per constructed mesh
+    pub(crate) origins: Vec<FaceOrigin>, // source mesh and face of each face
     pub collider: MortonCollider, //
     pub coplanar: Vec<i32>,       // indices of coplanar faces
 }
 
+// Where a face comes from: the id of the mesh it was built in and its face index there.
+// Boolean results keep the origins of their operands, so chained operations trace back to the inputs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct FaceOrigin {
+    pub mesh: usize,
+    pub face: usize,
+}
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
 impl Manifold {
     pub fn new(pos: &[f64], idx: &[usize]) -> Result<Self, String> {
 
@@ -70,10 +84,21 @@ impl Manifold {
         idx: Vec<Vec3u>,
         eps: Option<Real>,
         tol: Option<Real>,
+    ) -> Result<Self, String> {
+        Self::new_with_origins(ps, idx, None, eps, tol)
+    }
+
+    // The origins are given per face of idx, none makes every face its own origin.
+    pub(crate) fn new_with_origins(
+        ps : Vec<Vec3>,
+        idx: Vec<Vec3u>,
+        origins: Option<Vec<FaceOrigin>>,
+        eps: Option<Real>,
+        tol: Option<Real>,
     ) -> Result<Self, String> {
         let bb = BBox::new(None, &ps);
         let (mut f_bb, mut f_mt) = compute_face_morton(&ps, &idx, &bb);
-        let hm = sort_faces(&ps, &idx, &mut f_bb, &mut f_mt)?;
+        let (hm, map) = sort_faces(&ps, &idx, &mut f_bb, &mut f_mt)?;
         let hs = hm.half.iter().map(|&i| Half::new(hm.tail[i], hm.head[i], hm.twin[i])).collect::<Vec<_>>();
 
         let mut e = K_PRECISION * bb.scale();
@@ -82,6 +107,11 @@ impl Manifold {
         let tol = if let Some(t_) = tol { t_ } else { e };
         let collider = MortonCollider::new(&f_bb, &f_mt);
         let coplanar = compute_coplanar_idx(&ps, &hm.fns, &hs, eps);
+        let id = NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed);
+        let origins = match origins {
+            Some(os) => map.iter().map(|&i| os[i]).collect(),
+            None => (0..hm.nf).map(|face| FaceOrigin { mesh: id, face }).collect(),
+        };
 
         let mfd = Manifold {
             nv: hm.nv,
@@ -92,7 +122,8 @@ impl Manifold {
             bounding_box: bb,
             vert_normals: hm.vns,
             face_normals: hm.fns,
-            original_idx: vec![],
+            id,
+            origins,
             eps,
             tol,
             collider,
@@ -103,6 +134,20 @@ impl Manifold {
         Ok(mfd)
     }
 
+    /// Id of the mesh, unique among the meshes constructed in this process.
+    pub fn id(&self) -> usize { self.id }
+
+    /// Source mesh id and face index of each face. A boolean result keeps the origins of its operands.
+    pub fn face_origins(&self) -> &[FaceOrigin] { &self.origins }
+
+    /// Index into `sources` of the mesh each face comes from, e.g. as per-triangle color ids
+    /// with one palette entry per operand. Fails if a face comes from none of them.
+    pub fn source_indices(&self, sources: &[&Manifold]) -> Result<Vec<usize>, String> {
+        self.origins.iter().map(|o| {
+            sources.iter().position(|m| m.id == o.mesh).ok_or_else(|| "a face comes from none of the sources".to_string())
+        }).collect()
+    }
+
     pub fn is_manifold(&self) -> bool {
         self.hs.iter().enumerate().all(|(i, h)| {
             if h.tail().is_none() || h.head().is_none() { return true; }
@@ -167,13 +212,14 @@ fn sort_faces(
     idx: &[Vec3u],
     face_bboxes: &mut Vec<BBox>,
     face_morton: &mut Vec<u32>
-) -> Result<Hmesh, String> {
+) -> Result<(Hmesh, Vec<usize>), String> {
     let mut map = (0..face_morton.len()).collect::<Vec<_>>();
     map.sort_by_key(|&i| face_morton[i]);
     *face_bboxes = map.iter().map(|&i| face_bboxes[i].clone()).collect::<Vec<_>>();
     *face_morton = map.iter().map(|&i| face_morton[i]).collect::<Vec<_>>();
 
-    Hmesh::new(pos, &map.iter().map(|&i| idx[i]).collect::<Vec<_>>())
+    let hm = Hmesh::new(pos, &map.iter().map(|&i| idx[i]).collect::<Vec<_>>())?;
+    Ok((hm, map))
 }
 
 fn compute_coplanar_idx(
@@ -0,0 +1,76 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use crate::{Half, Manifold, next_of};
+
+/// Vertex adjacency of a manifold, built once by `Manifold::topology`.
+pub struct Topology<'a> {
+    hs: &'a [Half],
+    vh: Vec<usize>, // an outgoing halfedge per vertex, usize::MAX if unused
+}
+
+/// Outgoing halfedges around a vertex, clockwise seen from outside.
+pub struct Star<'a> {
+    hs: &'a [Half],
+    bgn: usize,
+    cur: Option<usize>,
+    cnt: usize, // bounds the walk in case the mesh is broken
+}
+
+impl Iterator for Star<'_> {
+    type Item = usize;
+    fn next(&mut self) -> Option<usize> {
+        let h = self.cur?;
+        self.cnt += 1;
+        let n = next_of(self.hs[h].pair);
+        self.cur = if n == self.bgn || self.cnt >= self.hs.len() { None } else { Some(n) };
+        Some(h)
+    }
+}
+
+impl Manifold {
+    /// Builds the vertex to halfedge index used by `Topology` to walk stars and one-rings.
+    pub fn topology(&self) -> Topology<'_> {
+        let mut vh = vec![usize::MAX; self.nv];
+        for (i, h) in self.hs.iter().enumerate() {
+            if vh[h.tail] == usize::MAX { vh[h.tail] = i; }
+        }
+        Topology { hs: &self.hs, vh }
+    }
+
+    /// Halfedges with tail < head, one per undirected edge. The other side is their pair.
+    pub fn edges(&self) -> impl Iterator<Item = usize> + '_ {
+        self.hs.iter().enumerate().filter(|(_, h)| h.is_forward()).map(|(i, _)| i)
+    }
+
+    /// Faces across the three edges of face `f`, in the order of its halfedges.
+    pub fn face_neighbors(&self, f: usize) -> [usize; 3] {
+        [0, 1, 2].map(|k| self.hs[f * 3 + k].pair / 3)
+    }
+}
+
+impl<'a> Topology<'a> {
+    /// An outgoing halfedge of vertex `v`, None if no triangle uses it.
+    pub fn halfedge(&self, v: usize) -> Option<usize> {
+        Some(self.vh[v]).filter(|&h| h != usize::MAX)
+    }
+
+    /// Outgoing halfedges of vertex `v`.
+    pub fn star(&self, v: usize) -> Star<'a> {
+        let h = self.halfedge(v);
+        Star { hs: self.hs, bgn: h.unwrap_or(0), cur: h, cnt: 0 }
+    }
+
+    /// Faces around vertex `v`, in the order of `star`.
+    pub fn vertex_faces(&self, v: usize) -> impl Iterator<Item = usize> + 'a {
+        self.star(v).map(|h| h / 3)
+    }
+
+    /// Vertices adjacent to vertex `v`, in the order of `star`.
+    pub fn one_ring(&self, v: usize) -> impl Iterator<Item = usize> + 'a {
+        let hs = self.hs;
+        self.star(v).map(move |h| hs[h].head)
+    }
+
+    pub fn valence(&self, v: usize) -> usize { self.star(v).count() }
+}
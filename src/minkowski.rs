@@ -0,0 +1,47 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use crate::{compute_boolean_all, Manifold, OpType, Vec3, Vec3u};
+
+/// Minkowski sum of two manifolds. The second operand is treated as convex,
+/// i.e. its convex hull is used if it is not. The result is exact when both are convex.
+/// Otherwise `a` is decomposed into its triangles, each of which is swept by `b` as a convex hull,
+/// and the sweeps are unioned together with `a` translated by a vertex of `b`.
+pub fn minkowski_sum(
+    a: &Manifold,
+    b: &Manifold
+) -> Result<Manifold, String> {
+    let b = if b.is_convex() { b.clone() } else { b.hull()? };
+
+    if a.is_convex() {
+        let ps = a.ps.iter().flat_map(|pa| b.ps.iter().map(move |pb| pa + pb)).collect::<Vec<_>>();
+        return Manifold::hull_of_points(&ps);
+    }
+
+    let mut ms = vec![translate(a, b.ps[0])?];
+    ms.extend(minkowski_faces(a, &b)?);
+    compute_boolean_all(&ms, OpType::Add)
+}
+
+// Convex hulls of every triangle of `a` swept by the convex `b`.
+// Together they cover the boundary of `a` dilated by `b`.
+pub(crate) fn minkowski_faces(
+    a: &Manifold,
+    b: &Manifold
+) -> Result<Vec<Manifold>, String> {
+    a.hs.chunks(3).map(|hs| {
+        let ps = hs.iter()
+            .flat_map(|h| b.ps.iter().map(|pb| a.ps[h.tail] + pb))
+            .collect::<Vec<_>>();
+        Manifold::hull_of_points(&ps)
+    }).collect()
+}
+
+fn translate(m: &Manifold, t: Vec3) -> Result<Manifold, String> {
+    Manifold::new_impl(
+        m.ps.iter().map(|p| p + t).collect(),
+        m.hs.chunks(3).map(|hs| Vec3u::new(hs[0].tail, hs[1].tail, hs[2].tail)).collect(),
+        Some(m.eps),
+        Some(m.tol)
+    )
+}
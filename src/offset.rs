@@ -0,0 +1,61 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::f64::consts::PI;
+use crate::minkowski::minkowski_faces;
+use crate::{compute_boolean, compute_boolean_all, compute_boolean_opt, BooleanOptions, Manifold, OpType, Real, Vec3};
+
+// Number of points on the polyhedral ball used as the offset kernel.
+const K_BALL_POINTS: usize = 32;
+
+impl Manifold {
+    /// Offsets the surface outward (positive distance) or inward (negative distance).
+    /// The offset is the Minkowski sum/difference with a polyhedral ball inscribed in the sphere
+    /// of the given radius. The ball has vertices on the axes, so axis-aligned flat regions move
+    /// exactly by the distance, while other regions may fall short by up to a tenth of it.
+    /// Self-intersections are resolved by the boolean union of the swept faces instead of
+    /// moving vertices along their normals. Fails if an inward offset erodes the whole solid.
+    pub fn offset(&self, distance: Real) -> Result<Manifold, String> {
+        self.offset_opt(distance)?.ok_or_else(|| "the offset erodes the whole solid".into())
+    }
+
+    /// Hollows the solid, leaving a wall of the given thickness inside the original surface.
+    /// The solid is returned as is when it is thinner than twice the thickness everywhere.
+    pub fn shell(&self, thickness: Real) -> Result<Manifold, String> {
+        if thickness <= 0. { return Err("shell thickness must be positive".into()); }
+        match self.offset_opt(-thickness)? {
+            Some(inner) => compute_boolean(self, &inner, OpType::Subtract),
+            None => Ok(self.clone()),
+        }
+    }
+
+    // Same as offset, but an inward offset removing everything is None.
+    fn offset_opt(&self, distance: Real) -> Result<Option<Manifold>, String> {
+        if distance == 0. { return Ok(Some(self.clone())); }
+
+        let ball = ball(distance.abs())?;
+        let mut ms = minkowski_faces(self, &ball)?;
+        if distance > 0. {
+            ms.push(self.clone());
+            compute_boolean_all(&ms, OpType::Add).map(Some)
+        } else {
+            let sweep = compute_boolean_all(&ms, OpType::Add)?;
+            compute_boolean_opt(self, &sweep, OpType::Subtract, &BooleanOptions::default())
+        }
+    }
+}
+
+// Convex hull of points evenly spread over a sphere by the golden angle spiral,
+// plus the six axis points so that the ball reaches the full radius along each axis.
+pub(crate) fn ball(radius: Real) -> Result<Manifold, String> {
+    let n = K_BALL_POINTS;
+    let golden = PI * (3. - (5f64).sqrt());
+    let mut ps = (0..n).map(|i| {
+        let z = 1. - 2. * (i as f64 + 0.5) / n as f64;
+        let r = (1. - z * z).sqrt();
+        let t = golden * i as f64;
+        Vec3::new((r * t.cos()) as Real, (r * t.sin()) as Real, z as Real) * radius
+    }).collect::<Vec<_>>();
+    for a in [Vec3::X, Vec3::Y, Vec3::Z] { ps.extend([a * radius, -a * radius]); }
+    Manifold::hull_of_points(&ps)
+}
@@ -0,0 +1,135 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::f64::consts::PI;
+use crate::{Manifold, Real, Vec3, Vec3u};
+
+// Vertices are ordered by (x, y, z) bits, faces are wound so that normals face outward.
+const CUBE_TS: [[usize; 3]; 12] = [
+    [1, 0, 4], [2, 4, 0],
+    [1, 3, 0], [3, 1, 5],
+    [3, 2, 0], [3, 7, 2],
+    [5, 4, 6], [5, 1, 4],
+    [6, 4, 2], [7, 6, 2],
+    [7, 3, 5], [7, 5, 6],
+];
+
+const TAU: Real = (PI * 2.) as Real;
+
+fn polar(r: Real, t: Real, z: Real) -> Vec3 {
+    Vec3::new(r * t.cos(), r * t.sin(), z)
+}
+
+fn tris(ts: &[[usize; 3]]) -> Vec<Vec3u> {
+    ts.iter().map(|t| Vec3u::new(t[0], t[1], t[2])).collect()
+}
+
+impl Manifold {
+    /// Axis aligned box spanning from the origin to `size`, or centered at the origin.
+    pub fn cube(size: Vec3, center: bool) -> Result<Manifold, String> {
+        if size.min_element() <= 0. { return Err("cube size must be positive".into()); }
+        let o = if center { size * -0.5 } else { Vec3::ZERO };
+        let ps = (0..8).map(|i| o + size * Vec3::new(
+            (i >> 2 & 1) as Real,
+            (i >> 1 & 1) as Real,
+            (i & 1) as Real,
+        )).collect();
+        Manifold::new_impl(ps, tris(&CUBE_TS), None, None)
+    }
+
+    /// Regular tetrahedron inscribed in the cube from -1 to 1.
+    pub fn tetrahedron() -> Result<Manifold, String> {
+        let ps = vec![
+            Vec3::new(-1., -1.,  1.),
+            Vec3::new(-1.,  1., -1.),
+            Vec3::new( 1., -1., -1.),
+            Vec3::new( 1.,  1.,  1.),
+        ];
+        Manifold::new_impl(ps, tris(&[[2, 0, 1], [0, 3, 1], [2, 3, 0], [3, 2, 1]]), None, None)
+    }
+
+    /// UV sphere centered at the origin, with `segments` divisions around the z axis
+    /// and half as many from pole to pole.
+    pub fn sphere(radius: Real, segments: usize) -> Result<Manifold, String> {
+        if radius <= 0. { return Err("sphere radius must be positive".into()); }
+        let ns = segments.max(3);
+        let nr = (ns / 2).max(2);
+
+        let mut ps = vec![Vec3::new(0., 0., radius)];
+        for i in 1..nr {
+            let phi = TAU * 0.5 * i as Real / nr as Real;
+            for j in 0..ns { ps.push(polar(radius * phi.sin(), TAU * j as Real / ns as Real, radius * phi.cos())); }
+        }
+        ps.push(Vec3::new(0., 0., -radius));
+
+        let s = ps.len() - 1;
+        let ring = |i: usize, j: usize| 1 + (i - 1) * ns + j % ns;
+        let mut ts = vec![];
+        for j in 0..ns {
+            ts.push([0, ring(1, j), ring(1, j + 1)]);
+            ts.push([s, ring(nr - 1, j + 1), ring(nr - 1, j)]);
+            for i in 1..nr - 1 {
+                let (a, b, c, d) = (ring(i, j), ring(i + 1, j), ring(i + 1, j + 1), ring(i, j + 1));
+                ts.push([a, b, c]);
+                ts.push([a, c, d]);
+            }
+        }
+        Manifold::new_impl(ps, tris(&ts), None, None)
+    }
+
+    /// Cylinder along the z axis from 0 to `height`, with radius `r0` at the bottom and `r1` at the top.
+    /// Either radius may be zero, which yields a cone with a single apex vertex.
+    pub fn cylinder(height: Real, r0: Real, r1: Real, segments: usize) -> Result<Manifold, String> {
+        if height <= 0. { return Err("cylinder height must be positive".into()); }
+        if r0 < 0. || r1 < 0. || r0.max(r1) == 0. { return Err("cylinder radii must be non-negative and not both zero".into()); }
+        let ns = segments.max(3);
+
+        let mut ps = vec![];
+        let mut ring = |r: Real, z: Real| -> Vec<usize> {
+            if r == 0. { ps.push(Vec3::new(0., 0., z)); return vec![ps.len() - 1; ns]; }
+            (0..ns).map(|j| {
+                ps.push(polar(r, TAU * j as Real / ns as Real, z));
+                ps.len() - 1
+            }).collect()
+        };
+        let bot = ring(r0, 0.);
+        let top = ring(r1, height);
+
+        let mut ts = vec![];
+        for j in 0..ns {
+            let k = (j + 1) % ns;
+            if r0 > 0. { ts.push([bot[j], bot[k], top[k]]); }
+            if r1 > 0. { ts.push([bot[j], top[k], top[j]]); }
+        }
+        for j in 1..ns - 1 {
+            if r0 > 0. { ts.push([bot[0], bot[j + 1], bot[j]]); }
+            if r1 > 0. { ts.push([top[0], top[j], top[j + 1]]); }
+        }
+        Manifold::new_impl(ps, tris(&ts), None, None)
+    }
+
+    /// Torus around the z axis, with `major` radius to the tube center and `minor` radius of the tube.
+    pub fn torus(major: Real, minor: Real, seg_major: usize, seg_minor: usize) -> Result<Manifold, String> {
+        if minor <= 0. || major <= minor { return Err("torus radii must satisfy 0 < minor < major".into()); }
+        let nu = seg_major.max(3);
+        let nv = seg_minor.max(3);
+
+        let mut ps = vec![];
+        for i in 0..nu {
+        for j in 0..nv {
+            let v = TAU * j as Real / nv as Real;
+            let u = TAU * i as Real / nu as Real;
+            ps.push(polar(major + minor * v.cos(), u, minor * v.sin()));
+        }}
+
+        let id = |i: usize, j: usize| (i % nu) * nv + j % nv;
+        let mut ts = vec![];
+        for i in 0..nu {
+        for j in 0..nv {
+            let (a, b, c, d) = (id(i, j), id(i + 1, j), id(i + 1, j + 1), id(i, j + 1));
+            ts.push([a, b, c]);
+            ts.push([a, c, d]);
+        }}
+        Manifold::new_impl(ps, tris(&ts), None, None)
+    }
+}
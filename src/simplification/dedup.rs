@@ -62,9 +62,6 @@ fn dedupe_edge(
         // Separate topological unit needs no new faces to be split
         let new_vert = ps.len();
         ps.push(ps[head]);
-        // Duplicate per-face data if present
-        ns.push(ns[head]);
-        rs.push(rs[head]);
         // Rewire the entire star around NextHalfedge(current) to new_vert
         let start = next_of(cur);
         let mut e = start;
@@ -87,9 +84,6 @@ fn dedupe_edge(
         // Split the pinched vert the previous split created.
         let new_vert = ps.len();
         ps.push(ps[head]);
-        // Duplicate per-face data if present
-        ns.push(ns[head]);
-        rs.push(rs[head]);
         let bgn = next_of(curr);
         let mut e = bgn;
         loop {
@@ -106,7 +106,10 @@ mod test_intersection {
 mod test_triangulation {
     use crate::triangulation::Pt;
     use crate::triangulation::ear_clip::EarClip;
-    use crate::{Vec2, Vec3, Vec3u};
+    use crate::triangulation::flat_tree::{compute_flat_tree, compute_query_flat_tree, Rect};
+    use crate::triangulation::triangulate_checked;
+    use crate::{dump_polygons, load_polygons};
+    use crate::{Real, Vec2, Vec3, Vec3u};
 
     #[test]
     fn test_ear_clip() {
@@ -132,6 +135,72 @@ mod test_triangulation {
         let res1 = EarClip::new(&polys, 1e-12).triangulate();
         for i in 0..3 { assert_eq!(res0[i], res1[i]); }
     }
+
+    #[test]
+    fn test_triangulate_checked() {
+        let pt = |idx: usize, x: Real, y: Real| Pt { idx, pos: Vec2::new(x, y) };
+        let pos = |polys: &[Vec<Pt>], i: usize| polys.iter().flatten().find(|p| p.idx == i).map(|p| Vec3::new(p.pos.x, p.pos.y, 0.)).unwrap();
+
+        let square = vec![vec![pt(0, 0., 0.), pt(1, 1., 0.), pt(2, 1., 1.), pt(3, 0., 1.)]];
+        assert!(triangulate_checked(&square, |i| pos(&square, i), &Vec3::Z, 1e-9, false).is_some());
+
+        // a bow tie cannot be triangulated without a flipped triangle
+        let bowtie = vec![vec![pt(0, 0., 0.), pt(1, 2., 2.), pt(2, 2., 0.), pt(3, 0., 2.)]];
+        assert!(triangulate_checked(&bowtie, |i| pos(&bowtie, i), &Vec3::Z, 1e-9, false).is_none());
+
+        let polys = vec![vec![Vec2::new(0.1, 1. / 3.), Vec2::new(-2e-17, 7.), Vec2::new(1e30, -0.)], vec![]];
+        let (back, eps) = load_polygons(&dump_polygons(&polys, 1e-12)).unwrap();
+        assert_eq!(back, polys);
+        assert_eq!(eps, 1e-12);
+        assert!(load_polygons("eps 1\n0 0\n").is_err());
+        // a truncated dump does not load as a shorter loop
+        let dump = dump_polygons(&polys[..1], 1e-12);
+        let cut = dump.trim_end().rsplit_once('\n').unwrap().0;
+        assert!(load_polygons(cut).is_err());
+        assert!(load_polygons("eps 1\nloop 3\n0 0\n1 0\nloop 3\n0 1\n1 1\n2 1\n").is_err());
+    }
+
+    // Numerical Recipes LCG, mapped to [0, 1)
+    fn lcg(s: &mut u64) -> Real {
+        *s = s.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (*s >> 11) as Real / (1u64 << 53) as Real
+    }
+
+    #[test]
+    fn test_flat_tree() {
+        let mut s = 7;
+        for n in [9, 10, 33, 100, 1000] {
+            let mut pts = (0..n).map(|idx| {
+                // snapping to a coarse grid yields many ties on the split axes
+                let pos = Vec2::new((lcg(&mut s) * 16.).floor(), (lcg(&mut s) * 16.).floor()) / 16.;
+                Pt { pos, idx }
+            }).collect::<Vec<_>>();
+            compute_flat_tree(&mut pts);
+            for _ in 0..50 {
+                let r = Rect::new(&Vec2::new(lcg(&mut s), lcg(&mut s)), &Vec2::new(lcg(&mut s), lcg(&mut s)));
+                let mut a = vec![];
+                let mut b = pts.iter().filter(|p| r.contains(&p.pos)).map(|p| p.idx).collect::<Vec<_>>();
+                compute_query_flat_tree(&pts, &r, |p| a.push(p.idx));
+                a.sort();
+                b.sort();
+                assert_eq!(a, b);
+            }
+        }
+
+        // star-shaped polygons large enough to go through the tree
+        for n in [16, 64, 512] {
+            let poly = (0..n).map(|i| {
+                let t = i as Real / n as Real * std::f64::consts::TAU as Real;
+                Pt { pos: Vec2::from_angle(t) * (0.2 + lcg(&mut s)), idx: i }
+            }).collect::<Vec<_>>();
+            let ts = EarClip::new(std::slice::from_ref(&poly), -1.).triangulate();
+            assert_eq!(ts.len(), n - 2);
+            let area = |t: &Vec3u| (poly[t.y].pos - poly[t.x].pos).perp_dot(poly[t.z].pos - poly[t.x].pos) * 0.5;
+            let total = (0..n).map(|i| poly[i].pos.perp_dot(poly[(i + 1) % n].pos) * 0.5).sum::<Real>();
+            assert!(ts.iter().all(|t| area(t) >= 0.));
+            assert!((ts.iter().map(area).sum::<Real>() - total).abs() < 1e-4);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -407,6 +476,39 @@ mod test_simplification {
             if !p0.x.is_nan() && !p0.y.is_nan() && p0.z.is_nan() { assert!((p0 - p1).length() < 1e-6); }
         }
     }
+
+    // The faces of a box swept by a ball touch each other, so their union pinches vertices in
+    // dedupe_edges, which must leave one face normal and reference per triangle.
+    #[test]
+    fn test_dedup_pinch() {
+        use crate::boolean03::boolean03;
+        use crate::boolean45::boolean45;
+        use crate::triangulation::triangulate;
+        use crate::simplification::simplify_topology;
+        use crate::minkowski::minkowski_faces;
+        use crate::offset::ball;
+        use crate::{compute_boolean, Manifold, OpType};
+        use super::test_minkowski::gen_box;
+
+        // same split as compute_boolean_all
+        fn union(ms: &[Manifold]) -> Manifold {
+            if ms.len() == 1 { return ms[0].clone(); }
+            let (l, r) = ms.split_at(ms.len() / 2);
+            let (p, q) = (union(l), union(r));
+            let eps = p.eps.max(q.eps);
+            let b03 = boolean03(&p, &q, &OpType::Add);
+            let mut b45 = boolean45(&p, &q, &b03, &OpType::Add);
+            let mut trg = triangulate(&p, &q, &b45, eps, false).unwrap();
+            simplify_topology(&mut trg.hs, &mut b45.ps, &mut trg.ns, &mut trg.rs, b45.nv_from_p, b45.nv_from_q, eps);
+            assert_eq!(trg.rs.len() * 3, trg.hs.len());
+            assert_eq!(trg.ns.len() * 3, trg.hs.len());
+            compute_boolean(&p, &q, OpType::Add).unwrap()
+        }
+
+        let a = gen_box([-1.; 3], [1.; 3]);
+        let ms = minkowski_faces(&a, &ball(1.2).unwrap()).unwrap();
+        assert!(union(&ms).is_manifold());
+    }
 }
 
 #[cfg(test)]
@@ -440,3 +542,912 @@ mod test_mesh_cleanup {
         assert_eq!(mfd.nf, 4);
     }
 }
+
+#[cfg(test)]
+mod test_hull {
+    use std::f64::consts::PI;
+    use crate::prelude::Manifold;
+    use crate::{Real, Vec3};
+
+    pub fn volume(mfd: &Manifold) -> Real {
+        mfd.hs.chunks(3).map(|hs| {
+            let p0 = mfd.ps[hs[0].tail];
+            let p1 = mfd.ps[hs[1].tail];
+            let p2 = mfd.ps[hs[2].tail];
+            p0.dot(p1.cross(p2))
+        }).sum::<Real>() / 6.
+    }
+
+    #[test]
+    fn test_hull_of_points() {
+        let mut pts = vec![];
+        for i in 0..27 {
+            let p = Vec3::new((i % 3) as Real, (i / 3 % 3) as Real, (i / 9) as Real) * 0.5;
+            pts.push(p);
+            pts.push(p); // duplicated
+        }
+        let mfd = Manifold::hull_of_points(&pts).unwrap();
+        assert_eq!(mfd.nv, 8);
+        assert_eq!(mfd.nf, 12);
+        assert!((volume(&mfd) - 1.).abs() < 1e-6);
+
+        let flat = pts.iter().map(|p| Vec3::new(p.x, p.y, 0.)).collect::<Vec<_>>();
+        assert!(Manifold::hull_of_points(&flat).is_err());
+    }
+
+    #[test]
+    fn test_hull_all() {
+        let pts = (0..200).map(|i| {
+            let t = i as Real * 2.399963;
+            let z = 1. - 2. * (i as Real + 0.5) / 200.;
+            let r = (1. - z * z).sqrt();
+            Vec3::new(r * t.cos(), r * t.sin(), z)
+        }).collect::<Vec<_>>();
+        let a = Manifold::hull_of_points(&pts).unwrap();
+        let b = Manifold::hull_of_points(&pts.iter().map(|p| p + Vec3::new(3., 0., 0.)).collect::<Vec<_>>()).unwrap();
+        assert_eq!(a.nv, 200);
+        assert!(a.is_manifold());
+        let ab = Manifold::hull_all(&[a.clone(), b]).unwrap();
+        assert!(volume(&ab) > volume(&a) * 2.);
+        assert!(volume(&ab) < (PI * 4. / 3. + PI * 3.) as Real);
+    }
+}
+
+#[cfg(test)]
+mod test_minkowski {
+    use crate::prelude::*;
+    use super::test_hull::volume;
+
+    pub fn gen_box(min: [f64; 3], max: [f64; 3]) -> Manifold {
+        let mut ps = vec![];
+        for i in 0..8 {
+            ps.push(if i & 4 == 0 { min[0] } else { max[0] });
+            ps.push(if i & 2 == 0 { min[1] } else { max[1] });
+            ps.push(if i & 1 == 0 { min[2] } else { max[2] });
+        }
+        let ts = [1, 0, 4, 2, 4, 0, 1, 3, 0, 3, 1, 5, 3, 2, 0, 3, 7, 2, 5, 4, 6, 5, 1, 4, 6, 4, 2, 7, 6, 2, 7, 3, 5, 7, 5, 6];
+        Manifold::new(&ps, &ts).unwrap()
+    }
+
+    #[test]
+    fn test_minkowski_convex() {
+        let a = gen_box([-0.5; 3], [0.5; 3]);
+        let b = gen_box([-0.5; 3], [0.5; 3]);
+        assert!(a.is_convex());
+        let m = minkowski_sum(&a, &b).unwrap();
+        assert_eq!(m.nv, 8);
+        assert!((volume(&m) - 8.).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_minkowski_non_convex() {
+        let l0 = gen_box([0., 0., 0.], [2., 1., 1.]);
+        let l1 = gen_box([0., 0., 0.], [1., 2., 1.]);
+        let l = compute_boolean(&l0, &l1, OpType::Add).unwrap();
+        assert!(!l.is_convex());
+        let b = gen_box([-0.25; 3], [0.25; 3]);
+        let m = minkowski_sum(&l, &b).unwrap();
+        assert!(m.is_manifold());
+        assert!((volume(&m) - 7.875).abs() < 1e-4);
+    }
+}
+
+#[cfg(test)]
+mod test_face_origins {
+    use crate::prelude::*;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_face_origins() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 1., 1.], [3., 3., 3.]);
+        let c = gen_box([-1., -1., -1.], [0.5, 0.5, 0.5]);
+        assert!(a.face_origins().iter().enumerate().all(|(face, &o)| o == FaceOrigin { mesh: a.id(), face }));
+
+        let ab = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let m = compute_boolean(&ab, &c, OpType::Add).unwrap();
+        assert_eq!(m.face_origins().len(), m.nf);
+        assert_eq!(m.source_indices(&[&a, &b, &c]).unwrap().len(), m.nf);
+        assert!(m.source_indices(&[&a, &b]).is_err());
+
+        // every face lies in the plane of the face it came from
+        for s in [&a, &b, &c] {
+            let fs = (0..m.nf).filter(|&f| m.face_origins()[f].mesh == s.id()).collect::<Vec<_>>();
+            assert!(!fs.is_empty());
+            for f in fs {
+                let g = m.face_origins()[f].face;
+                let n = s.face_normals[g];
+                assert!(m.face_normals[f].dot(n).abs() > 0.999);
+                assert!((m.ps[m.hs[f * 3].tail] - s.ps[s.hs[g * 3].tail]).dot(n).abs() < 1e-9);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_offset {
+    use super::test_hull::volume;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_offset_and_shell() {
+        let a = gen_box([-1.; 3], [1.; 3]);
+        let outer = a.offset(0.25).unwrap();
+        let v = volume(&outer);
+        assert!(outer.is_manifold());
+        assert!(v > 13.5 && v < 15.25);
+        // axis-aligned faces move by the full distance
+        let bb = &outer.bounding_box;
+        assert!((bb.min + 1.25).abs().max_element() < 1e-6 && (bb.max - 1.25).abs().max_element() < 1e-6);
+
+        let inner = a.offset(-0.25).unwrap();
+        let v = volume(&inner);
+        assert!((v - 3.375).abs() < 1e-6);
+
+        let shell = a.shell(0.25).unwrap();
+        assert!((volume(&shell) - (8. - v)).abs() < 1e-4);
+
+        // eroding past the half width leaves nothing
+        assert_eq!(a.offset(-1.2).unwrap_err(), "the offset erodes the whole solid");
+        assert!((volume(&a.shell(1.2).unwrap()) - 8.).abs() < 1e-6);
+    }
+}
+
+#[cfg(all(test, feature = "primitives"))]
+mod test_primitives {
+    use std::f64::consts::PI;
+    use crate::prelude::*;
+    use crate::{Real, Vec3};
+    use super::test_hull::volume;
+
+    #[test]
+    fn test_primitives() {
+        let pi = PI as Real;
+        let cube = Manifold::cube(Vec3::new(1., 2., 3.), false).unwrap();
+        assert!((volume(&cube) - 6.).abs() < 1e-4);
+
+        let tet = Manifold::tetrahedron().unwrap();
+        assert!((volume(&tet) - 8. / 3.).abs() < 1e-4);
+
+        let sphere = Manifold::sphere(1., 64).unwrap();
+        assert!(volume(&sphere) < 4. / 3. * pi && volume(&sphere) > 4. / 3. * pi * 0.97);
+
+        let cylinder = Manifold::cylinder(2., 1., 1., 64).unwrap();
+        assert!(volume(&cylinder) < 2. * pi && volume(&cylinder) > 2. * pi * 0.99);
+
+        let cone = Manifold::cylinder(3., 1., 0., 64).unwrap();
+        assert!(volume(&cone) < pi && volume(&cone) > pi * 0.99);
+        assert_eq!(cone.nv, 65);
+
+        let torus = Manifold::torus(2., 0.5, 64, 32).unwrap();
+        let v = 2. * pi * pi * 2. * 0.25;
+        assert!(volume(&torus) < v && volume(&torus) > v * 0.98);
+
+        for m in [&cube, &tet, &sphere, &cylinder, &cone, &torus] { assert!(m.is_manifold()); }
+        let res = compute_boolean(&sphere, &cube, OpType::Subtract).unwrap();
+        assert!(volume(&res) < volume(&sphere));
+    }
+}
+
+#[cfg(test)]
+mod test_extrusion {
+    use crate::prelude::*;
+    use crate::{Real, Vec2};
+    use super::test_hull::volume;
+
+    pub fn square(c: Real, h: Real) -> Vec<Vec2> {
+        vec![Vec2::new(c - h, c - h), Vec2::new(c + h, c - h), Vec2::new(c + h, c + h), Vec2::new(c - h, c + h)]
+    }
+
+    #[test]
+    fn test_extrude() {
+        let mut hole = square(0., 0.5);
+        hole.reverse();
+        let polys = vec![square(0., 1.), hole];
+
+        let m = Manifold::extrude(&polys, 2., 0, 0., Vec2::ONE).unwrap();
+        assert!((volume(&m) - 6.).abs() < 1e-6);
+
+        let m = Manifold::extrude(&polys, 2., 8, 45., Vec2::new(0.5, 0.5)).unwrap();
+        assert!(m.is_manifold() && volume(&m) > 0.);
+
+        let cone = Manifold::extrude(&[square(0., 1.)], 3., 0, 0., Vec2::ZERO).unwrap();
+        assert_eq!(cone.nv, 5);
+        assert!((volume(&cone) - 4.).abs() < 1e-6);
+        assert_eq!(cone.topology().valence(4), 4);
+
+        // holes would meet the outer loop at the apex
+        assert!(Manifold::extrude(&polys, 3., 0, 0., Vec2::ZERO).is_err());
+
+        // clockwise input is flipped as a whole
+        let mut cw = square(0.5, 1.);
+        cw.reverse();
+        let other = Manifold::extrude(&[cw], 1., 0, 0., Vec2::ONE).unwrap();
+        let res = compute_boolean(&m, &other, OpType::Subtract).unwrap();
+        assert!(volume(&res) > 0. && volume(&res) < volume(&m));
+    }
+}
+
+#[cfg(test)]
+mod test_revolution {
+    use std::f64::consts::PI;
+    use crate::prelude::*;
+    use crate::{Real, Vec2};
+    use super::test_hull::volume;
+    use super::test_extrusion::square;
+
+    #[test]
+    fn test_revolve() {
+        let pi = PI as Real;
+        let ring = Manifold::revolve(&[square(1.5, 0.5)], 64, 360.).unwrap();
+        assert!(volume(&ring) < 3. * pi && volume(&ring) > 3. * pi * 0.99);
+
+        let half = Manifold::revolve(&[square(1.5, 0.5)], 64, 180.).unwrap();
+        assert!((volume(&half) - volume(&ring) / 2.).abs() < 1e-4);
+
+        // vertices on the axis are not duplicated
+        let tri = vec![Vec2::new(0., 0.), Vec2::new(1., 0.), Vec2::new(0., 1.)];
+        let cone = Manifold::revolve(std::slice::from_ref(&tri), 64, 360.).unwrap();
+        assert_eq!(cone.nv, 66);
+        assert!(volume(&cone) < pi / 3. && volume(&cone) > pi / 3. * 0.99);
+
+        let wedge = Manifold::revolve(&[tri], 16, 90.).unwrap();
+        assert!(wedge.is_manifold());
+        let res = compute_boolean(&ring, &cone, OpType::Add).unwrap();
+        assert!((volume(&res) - volume(&ring) - volume(&cone)).abs() < 1e-4);
+    }
+}
+
+#[cfg(test)]
+mod test_sweep {
+    use crate::prelude::*;
+    use crate::Vec3;
+    use super::test_hull::volume;
+    use super::test_extrusion::square;
+
+    #[test]
+    fn test_sweep() {
+        let prof = square(0., 0.25);
+        let path = [Vec3::new(0., 0., 0.), Vec3::new(0., 0., 2.), Vec3::new(2., 0., 2.), Vec3::new(2., 1., 3.)];
+        let m = Manifold::sweep(&prof, &path, &SweepOptions::default()).unwrap();
+        let len = 4. + (2. as crate::Real).sqrt();
+        assert!((volume(&m) - 0.25 * len).abs() < 1e-4);
+
+        let ring = [Vec3::new(0., 0., 0.), Vec3::new(2., 0., 0.), Vec3::new(2., 2., 0.), Vec3::new(0., 2., 0.)];
+        let opts = SweepOptions { closed: true, ..Default::default() };
+        let m = Manifold::sweep(&prof, &ring, &opts).unwrap();
+        assert!((volume(&m) - 0.25 * 8.).abs() < 1e-4);
+
+        // a hairpin bend tighter than the profile
+        let path = [Vec3::new(0., 0., 0.), Vec3::new(0., 0., 2.), Vec3::new(0.3, 0., 0.)];
+        let opts = SweepOptions { resolve_overlap: true, ..Default::default() };
+        let m = Manifold::sweep(&prof, &path, &opts).unwrap();
+        assert!(m.is_manifold());
+        assert!(volume(&m) > 0.25 * 2. && volume(&m) < 0.25 * 4.1);
+    }
+}
+
+#[cfg(test)]
+mod test_loft {
+    use crate::prelude::*;
+    use crate::{Real, Vec2, Vec3};
+    use super::test_hull::volume;
+    use super::test_extrusion::square;
+
+    #[test]
+    fn test_loft() {
+        // frustum from a square to a smaller one with midpoints
+        let top = [(-0.5, -0.5), (0., -0.5), (0.5, -0.5), (0.5, 0.), (0.5, 0.5), (0., 0.5), (-0.5, 0.5), (-0.5, 0.)]
+            .map(|(x, y)| Vec2::new(x, y)).to_vec();
+        let secs = vec![
+            (Plane::new(Vec3::ZERO, Vec3::Z), square(0., 1.)),
+            (Plane::new(Vec3::Z, Vec3::Z), top),
+        ];
+        let m = Manifold::loft(&secs).unwrap();
+        assert!(m.is_manifold());
+        assert!((volume(&m) - 7. / 3.).abs() < 1e-4);
+
+        // square to circle through a tilted plane, given in reverse order
+        let circle = (0..24).map(|i| Vec2::from_angle(i as Real / 24. * std::f64::consts::TAU as Real)).collect::<Vec<_>>();
+        let secs = vec![
+            (Plane::new(Vec3::new(0., 0., 3.), Vec3::new(0., 0.2, 1.)), circle),
+            (Plane::new(Vec3::new(0., 0., 1.5), Vec3::Z), square(0., 0.8)),
+            (Plane::new(Vec3::ZERO, Vec3::Z), square(0., 1.)),
+        ];
+        let m = Manifold::loft(&secs).unwrap();
+        assert!(m.is_manifold());
+        assert!(volume(&m) > 1.5 * 2.56 && volume(&m) < 3. * 4.);
+    }
+}
+
+#[cfg(test)]
+mod test_cross_section {
+    use crate::prelude::*;
+    use super::test_extrusion::square;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_cross_section_boolean() {
+        let a = CrossSection::new(&[square(0., 1.)]).unwrap();
+        let b = CrossSection::new(&[square(1., 1.)]).unwrap();
+        assert!((a.boolean(&b, OpType::Add).unwrap().area() - 7.).abs() < 1e-4);
+        assert!((a.boolean(&b, OpType::Intersect).unwrap().area() - 1.).abs() < 1e-4);
+
+        let hole = CrossSection::new(&[square(0., 0.5)]).unwrap();
+        let r = a.boolean(&hole, OpType::Subtract).unwrap();
+        assert_eq!(r.polygons().len(), 2);
+        assert!((r.area() - 3.).abs() < 1e-4);
+        assert!((r.extrude(2.).unwrap().slice(1.).area() - 3.).abs() < 1e-4);
+
+        let far = CrossSection::new(&[square(5., 1.)]).unwrap();
+        assert!(a.boolean(&far, OpType::Intersect).unwrap().is_empty());
+
+        let s = gen_box([0., 0., 0.], [2., 3., 1.]).slice(0.5);
+        assert!((s.area() - 6.).abs() < 1e-4);
+        let (lo, hi) = s.bounds();
+        assert_eq!(hi - lo, crate::Vec2::new(2., 3.));
+    }
+
+    #[test]
+    fn test_cross_section_offset() {
+        let a = CrossSection::new(&[square(0., 1.)]).unwrap();
+        let g = a.offset(0.5, 16).unwrap();
+        assert!(g.area() > 4. + 4. + 0.7 && g.area() < 4. + 4. + 0.8);
+        let s = a.offset(-0.5, 16).unwrap();
+        assert!((s.area() - 1.).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_slice_touching() {
+        // contours sharing a corner stay apart
+        let a = gen_box([0., 0., 0.], [1., 1., 1.]);
+        let b = gen_box([1., 1., 0.], [2., 2., 1.]);
+        let m = compute_boolean(&a, &b, OpType::Add).unwrap();
+        let s = m.slice(0.5);
+        assert_eq!(s.polygons().len(), 2);
+        assert!((s.area() - 2.).abs() < 1e-4);
+
+        // the union of prisms over the triangles of a ring leaves walls touching along the seams,
+        // and the contour running through them is split into the outer loop and the hole
+        let c = gen_box([0.5, 0.5, -1.], [1.5, 1.5, 2.]);
+        let ring = compute_boolean(&gen_box([0., 0., 0.], [2., 2., 1.]), &c, OpType::Subtract).unwrap();
+        let ps = ring.positions_f64();
+        let prisms = ring.triangles().iter().enumerate()
+            .filter(|(f, _)| ring.face_normals[*f].z > 0.5)
+            .map(|(_, t)| {
+                let t = t.map(|i| crate::Vec2::new(ps[i][0] as crate::Real, ps[i][1] as crate::Real)).to_vec();
+                Manifold::extrude(&[t], 1., 0, 0., crate::Vec2::ONE).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let r = compute_boolean_all(&prisms, OpType::Add).unwrap().slice(0.5);
+        assert_eq!(r.polygons().len(), 2);
+        assert!((r.area() - 3.).abs() < 1e-4);
+    }
+}
+
+#[cfg(test)]
+mod test_project {
+    use std::f64::consts::PI;
+    use crate::prelude::*;
+    use crate::{Real, Vec2, Vec3};
+    use super::test_minkowski::gen_box;
+
+    fn area(ps: &[Vec<Vec2>]) -> Real {
+        CrossSection::new(ps).unwrap().area()
+    }
+
+    #[test]
+    fn test_project() {
+        let a = gen_box([0., 0., 0.], [2., 2., 1.]);
+        let b = gen_box([0.5, 0.5, -1.], [1.5, 1.5, 2.]);
+        let m = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let s = m.project(Vec3::Z).unwrap();
+        assert_eq!(s.len(), 2);
+        assert!((area(&s) - 3.).abs() < 1e-4);
+        assert!((area(&m.project(-Vec3::X).unwrap()) - 2.).abs() < 1e-4);
+
+        let c = gen_box([0., 0., 0.], [1., 1., 1.]);
+        let s = c.project(Vec3::ONE).unwrap();
+        assert_eq!(s.len(), 1);
+        assert!((area(&s) - (3. as Real).sqrt()).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_project_curved() {
+        let pi = PI as Real;
+        let arc = |c: Vec2, r: Real, a0: Real, a1: Real, n: usize| (0..n)
+            .map(|i| c + Vec2::from_angle(a0 + (a1 - a0) * i as Real / n as Real) * r)
+            .collect::<Vec<_>>();
+        let dir = Vec3::new(0.3, 0.2, 1.).normalize();
+
+        // a convex solid has a single outline, without slivers along the rim
+        let mut half = arc(Vec2::ZERO, 1., -pi / 2., pi / 2., 48);
+        half.push(Vec2::new(0., 1.));
+        let sphere = Manifold::revolve(&[half], 96, 360.).unwrap();
+        let s = sphere.project(dir).unwrap();
+        assert_eq!(s.len(), 1);
+        assert!(area(&s) < pi && area(&s) > pi * 0.99);
+
+        // the tube overlaps itself seen at an angle, which leaves the outline and the hole
+        let torus = Manifold::revolve(&[arc(Vec2::new(2., 0.), 0.5, 0., 2. * pi, 32)], 64, 360.).unwrap();
+        let s = torus.project(Vec3::Z).unwrap();
+        assert_eq!(s.len(), 2);
+        assert!(area(&s) < 4. * pi && area(&s) > 4. * pi * 0.99);
+        let s = torus.project(dir).unwrap();
+        assert_eq!(s.len(), 2);
+        assert!(area(&s) < 4. * pi && area(&s) > 4. * pi * dir.z);
+    }
+}
+
+#[cfg(test)]
+mod test_triangulate_polygons {
+    use crate::{triangulate_polygons, triangulate_polygons_3d, Real, Vec3};
+    use super::test_extrusion::square;
+
+    #[test]
+    fn test_triangulate_polygons() {
+        let mut hole = square(0., 0.5);
+        hole.reverse();
+        let polys = vec![square(0., 1.), hole];
+        let ps = polys.concat();
+        let ts = triangulate_polygons(&polys, -1.);
+        assert_eq!(ts.len(), 8);
+        let area = ts.iter().map(|t| (ps[t[1]] - ps[t[0]]).perp_dot(ps[t[2]] - ps[t[0]]) * 0.5).collect::<Vec<Real>>();
+        assert!(area.iter().all(|&a| a > 0.));
+        assert!((area.iter().sum::<Real>() - 3.).abs() < 1e-6);
+
+        // the same polygons on a tilted plane
+        let (u, v) = (Vec3::new(0., 0.6, 0.8), Vec3::new(-1., 0., 0.));
+        let polys3 = polys.iter().map(|p| p.iter().map(|q| u * q.x + v * q.y).collect()).collect::<Vec<Vec<Vec3>>>();
+        let ps = polys3.concat();
+        let ts = triangulate_polygons_3d(&polys3, -1.);
+        assert_eq!(ts.len(), 8);
+        let n = u.cross(v);
+        let area = ts.iter().map(|t| (ps[t[1]] - ps[t[0]]).cross(ps[t[2]] - ps[t[0]]).dot(n) * 0.5).collect::<Vec<Real>>();
+        assert!(area.iter().all(|&a| a > 0.));
+        assert!((area.iter().sum::<Real>() - 3.).abs() < 1e-5);
+    }
+}
+
+#[cfg(test)]
+mod test_delaunay {
+    use crate::prelude::*;
+    use crate::{Real, Vec2};
+    use super::test_hull::volume;
+    use super::test_minkowski::gen_box;
+
+    fn min_angle(m: &Manifold) -> Real {
+        m.hs.chunks(3).map(|hs| {
+            (0..3).map(|i| {
+                let p = m.ps[hs[i].tail];
+                let a = m.ps[hs[(i + 1) % 3].tail] - p;
+                let b = m.ps[hs[(i + 2) % 3].tail] - p;
+                a.angle_between(b)
+            }).fold(Real::MAX, Real::min)
+        }).fold(Real::MAX, Real::min)
+    }
+
+    #[test]
+    fn test_delaunay() {
+        let a = gen_box([-4., -1., 0.], [4., 1., 1.]);
+        let circle = (0..32).map(|i| Vec2::from_angle(i as Real / 32. * std::f64::consts::TAU as Real) * 0.5).collect::<Vec<_>>();
+        let b = Manifold::extrude(&[circle], 3., 0, 0., Vec2::ONE).unwrap();
+        let opts = BooleanOptions { delaunay: true };
+        let m0 = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let m1 = compute_boolean_with_options(&a, &b, OpType::Subtract, &opts).unwrap();
+        assert!(m1.is_manifold());
+        assert!((volume(&m0) - volume(&m1)).abs() < 1e-4);
+        assert!(min_angle(&m1) > min_angle(&m0));
+    }
+}
+
+#[cfg(all(test, feature = "obj"))]
+mod test_obj {
+    use crate::prelude::*;
+    use super::test_hull::volume;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_obj() {
+        let a = gen_box([0., 0., 0.], [1., 2., 3.]);
+        let b = gen_box([5., 0., 0.], [6., 1., 1.]);
+        let mut buf = vec![];
+        Manifold::write_obj_objects(&[("a", &a), ("b", &b)], &mut buf).unwrap();
+        let m = Manifold::from_obj(&mut buf.as_slice()).unwrap();
+        assert_eq!(m.nf, 24);
+        assert!((volume(&m) - 7.).abs() < 1e-6);
+        let objs = Manifold::objects_from_obj(&mut buf.as_slice()).unwrap();
+        assert_eq!(objs.iter().map(|(n, _)| n.as_str()).collect::<Vec<_>>(), ["a", "b"]);
+
+        // quad faces are triangulated on load
+        let quads = "v 0 0 0\nv 1 0 0\nv 1 1 0\nv 0 1 0\nv 0 0 1\nv 1 0 1\nv 1 1 1\nv 0 1 1\n\
+                     f 1 4 3 2\nf 5 6 7 8\nf 1 2 6 5\nf 2 3 7 6\nf 3 4 8 7\nf 4 1 5 8\n";
+        let m = Manifold::from_obj(&mut quads.as_bytes()).unwrap();
+        assert_eq!(m.nf, 12);
+        assert!((volume(&m) - 1.).abs() < 1e-6);
+    }
+}
+
+#[cfg(test)]
+mod test_stl {
+    use crate::prelude::*;
+    use super::test_hull::volume;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_stl() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 1., 1.], [3., 3., 3.]);
+        let m = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+
+        let mut bin = vec![];
+        m.write_stl_binary(&mut bin).unwrap();
+        assert_eq!(bin.len(), 84 + 50 * m.nf);
+        let mb = Manifold::from_stl(&mut bin.as_slice()).unwrap();
+        assert_eq!((mb.nv, mb.nf), (m.nv, m.nf));
+        assert!((volume(&mb) - 7.).abs() < 1e-5);
+
+        let mut txt = vec![];
+        m.write_stl_ascii(&mut txt).unwrap();
+        let ma = Manifold::from_stl(&mut txt.as_slice()).unwrap();
+        assert_eq!((ma.nv, ma.nf), (m.nv, m.nf));
+
+        // duplicated vertices printed with slightly differing digits are still welded
+        let txt = String::from_utf8(txt).unwrap().replacen("vertex 2 ", "vertex 2.0000000001 ", 1);
+        let mt = Manifold::from_stl(&mut txt.as_bytes()).unwrap();
+        assert_eq!((mt.nv, mt.nf), (m.nv, m.nf));
+        assert!(mt.is_manifold());
+
+        // the tolerance follows the size of the mesh rather than its distance from the origin
+        let far = gen_box([1e4, 0., 0.], [1e4 + 5e-3, 5e-3, 5e-3]);
+        let mut txt = vec![];
+        far.write_stl_ascii(&mut txt).unwrap();
+        let mf = Manifold::from_stl(&mut txt.as_slice()).unwrap();
+        assert_eq!((mf.nv, mf.nf), (8, 12));
+    }
+}
+
+#[cfg(test)]
+mod test_ply {
+    use crate::prelude::*;
+    use super::test_hull::volume;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_ply_round_trip() {
+        let m = gen_box([0., 0., 0.], [1., 2., 3.]);
+        let props = VertexProperties {
+            names: vec!["red".into(), "confidence".into()],
+            values: vec![
+                (0..m.nv).map(|i| (i * 30) as f64).collect(),
+                (0..m.nv).map(|i| 0.1 * i as f64).collect(),
+            ],
+        };
+        for f in [PlyFormat::Ascii, PlyFormat::BinaryLittleEndian, PlyFormat::BinaryBigEndian] {
+            let mut buf = vec![];
+            m.write_ply(&mut buf, Some(&props), f).unwrap();
+            let (r, p) = Manifold::from_ply(&mut buf.as_slice()).unwrap();
+            assert_eq!((r.nv, r.nf), (m.nv, m.nf));
+            assert_eq!(r.ps, m.ps);
+            assert_eq!(p, props);
+        }
+    }
+
+    #[test]
+    fn test_ply_quads() {
+        // a unit cube with quad faces, uchar colors and an extra element to skip
+        let txt = "ply\nformat ascii 1.0\ncomment cube\n\
+            element vertex 8\nproperty float x\nproperty float y\nproperty float z\nproperty uchar red\n\
+            element edge 1\nproperty int vertex1\nproperty int vertex2\n\
+            element face 6\nproperty list uchar int vertex_indices\nend_header\n\
+            0 0 0 0\n1 0 0 1\n1 1 0 2\n0 1 0 3\n0 0 1 4\n1 0 1 5\n1 1 1 6\n0 1 1 7\n\
+            0 1\n\
+            4 0 3 2 1\n4 4 5 6 7\n4 0 1 5 4\n4 1 2 6 5\n4 2 3 7 6\n4 3 0 4 7\n";
+        let (m, p) = Manifold::from_ply(&mut txt.as_bytes()).unwrap();
+        assert_eq!((m.nv, m.nf), (8, 12));
+        assert!((volume(&m) - 1.).abs() < 1e-5);
+        assert_eq!(p.names, vec!["red".to_string()]);
+        assert_eq!(p.values[0], (0..8).map(|i| i as f64).collect::<Vec<_>>());
+    }
+}
+
+#[cfg(all(test, feature = "threemf"))]
+mod test_threemf {
+    use crate::prelude::*;
+    use super::test_minkowski::gen_box;
+
+    // Reads the names and contents of a stored zip through its local headers.
+    fn unzip(buf: &[u8]) -> Vec<(String, String)> {
+        let mut r = vec![];
+        let mut o = 0;
+        let u16_at = |o: usize| u16::from_le_bytes([buf[o], buf[o + 1]]) as usize;
+        while buf[o..o + 4] == [0x50, 0x4b, 0x03, 0x04] {
+            let len = u32::from_le_bytes(buf[o + 18..o + 22].try_into().unwrap()) as usize;
+            let (nlen, xlen) = (u16_at(o + 26), u16_at(o + 28));
+            let name = String::from_utf8(buf[o + 30..o + 30 + nlen].to_vec()).unwrap();
+            let bgn = o + 30 + nlen + xlen;
+            r.push((name, String::from_utf8(buf[bgn..bgn + len].to_vec()).unwrap()));
+            o = bgn + len;
+        }
+        r
+    }
+
+    #[test]
+    fn test_3mf() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 1., 1.], [3., 3., 3.]);
+        let m = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let ids = (0..m.nf).map(|f| f % 2).collect::<Vec<_>>();
+        let options = ThreeMfOptions { unit: ThreeMfUnit::Inch, colors: vec![[255, 0, 0, 255], [0, 0, 255, 255]] };
+        let objects = [
+            ThreeMfObject { name: "a & b", manifold: &m, color_ids: Some(&ids) },
+            ThreeMfObject { name: "box", manifold: &b, color_ids: None },
+        ];
+        let mut buf = vec![];
+        Manifold::write_3mf_objects(&objects, &options, &mut buf).unwrap();
+
+        // the end record points at a central directory that ends right before it
+        let e = buf.len() - 22;
+        let at = |o: usize| u32::from_le_bytes(buf[o..o + 4].try_into().unwrap()) as usize;
+        assert_eq!((at(e), at(at(e + 16))), (0x06054b50, 0x02014b50));
+        assert_eq!(at(e + 12) + at(e + 16), e);
+
+        let es = unzip(&buf);
+        assert_eq!(es.iter().map(|e| e.0.as_str()).collect::<Vec<_>>(), ["[Content_Types].xml", "_rels/.rels", "3D/3dmodel.model"]);
+        let model = &es[2].1;
+        assert!(model.contains(r#"unit="inch""#));
+        assert!(model.contains(r##"<m:color color="#0000FFFF"/>"##));
+        assert!(model.contains(r#"name="a &amp; b""#));
+        assert_eq!(model.matches("<vertex ").count(), m.nv + b.nv);
+        assert_eq!(model.matches("<triangle ").count(), m.nf + b.nf);
+        assert_eq!(model.matches(r#"pid="1" p1="#).count(), m.nf);
+        assert_eq!(model.matches("<item ").count(), 2);
+
+        let bad = [ThreeMfObject { name: "a", manifold: &m, color_ids: Some(&ids[1..]) }];
+        assert!(Manifold::write_3mf_objects(&bad, &options, &mut vec![]).is_err());
+    }
+
+    #[test]
+    fn test_3mf_origins() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 1., 1.], [3., 3., 3.]);
+        let m = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let ids = m.source_indices(&[&a, &b]).unwrap();
+        let options = ThreeMfOptions { colors: vec![[255, 0, 0, 255], [0, 0, 255, 255]], ..Default::default() };
+        let objects = [ThreeMfObject { name: "a - b", manifold: &m, color_ids: Some(&ids) }];
+        let mut buf = vec![];
+        Manifold::write_3mf_objects(&objects, &options, &mut buf).unwrap();
+
+        // the faces cut by b take its color
+        let model = &unzip(&buf)[2].1;
+        let nb = m.face_origins().iter().filter(|o| o.mesh == b.id()).count();
+        assert!(nb > 0 && nb < m.nf);
+        assert_eq!(model.matches(r#"p1="1""#).count(), nb);
+        assert_eq!(model.matches(r#"p1="0""#).count(), m.nf - nb);
+    }
+}
+
+#[cfg(test)]
+mod test_gltf {
+    use crate::prelude::*;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_glb() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 1., 1.], [3., 3., 3.]);
+        let m = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let props = VertexProperties { names: vec!["red".into()], values: vec![vec![0.5; b.nv]] };
+        let meshes = [
+            GltfMesh { name: "diff", manifold: &m, attributes: None },
+            GltfMesh { name: "box", manifold: &b, attributes: Some(&props) },
+        ];
+
+        for (normals, nv) in [(GltfNormals::Face, m.nh), (GltfNormals::Smooth, m.nv)] {
+            let mut buf = vec![];
+            Manifold::write_glb_meshes(&meshes, normals, &mut buf).unwrap();
+            let at = |o: usize| u32::from_le_bytes(buf[o..o + 4].try_into().unwrap()) as usize;
+            assert_eq!((&buf[0..4], at(4), at(8)), (&b"glTF"[..], 2, buf.len()));
+            let jlen = at(12);
+            let json = std::str::from_utf8(&buf[20..20 + jlen]).unwrap();
+            assert_eq!(at(20 + jlen) + 28 + jlen, buf.len());
+            assert!(json.contains(&format!(r#""count":{nv},"type":"VEC3","min""#)));
+            assert!(json.contains(r#""NORMAL":1"#));
+            assert!(json.contains(r#""_RED":"#));
+            assert_eq!(json.matches(r#""mesh":"#).count(), 2);
+        }
+    }
+
+    #[test]
+    fn test_gltf() {
+        let m = gen_box([0., 0., 0.], [1., 1., 1.]);
+        let mut buf = vec![];
+        m.write_gltf(GltfNormals::None, &mut buf).unwrap();
+        let json = String::from_utf8(buf).unwrap();
+        assert!(!json.contains("NORMAL"));
+        // positions and indices of the 8 vertices and 12 triangles
+        let len: usize = 8 * 12 + 36 * 4;
+        assert!(json.contains(&format!(r#""byteLength":{len},"uri":"data:application/octet-stream;base64,"#)));
+        let b64 = json.split("base64,").nth(1).unwrap().trim_end_matches("\"}]}");
+        assert_eq!(b64.len(), len.div_ceil(3) * 4);
+    }
+}
+
+#[cfg(test)]
+mod test_off_raw {
+    use crate::prelude::*;
+    use super::test_hull::volume;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_raw() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 0.5, 1.], [3., 1.5, 3.]);
+        let m = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let mut buf = vec![];
+        m.save_raw(&mut buf).unwrap();
+        let r = Manifold::load_raw(&mut buf.as_slice()).unwrap();
+        assert_eq!(r.ps.iter().map(|p| p.to_array().map(|x| x.to_bits())).collect::<Vec<_>>(),
+                   m.ps.iter().map(|p| p.to_array().map(|x| x.to_bits())).collect::<Vec<_>>());
+        assert_eq!(r.hs.iter().map(|h| (h.tail, h.head, h.pair)).collect::<Vec<_>>(),
+                   m.hs.iter().map(|h| (h.tail, h.head, h.pair)).collect::<Vec<_>>());
+        assert_eq!((r.eps.to_bits(), r.tol.to_bits()), (m.eps.to_bits(), m.tol.to_bits()));
+
+        assert!(Manifold::load_raw(&mut &buf[..buf.len() - 1]).is_err());
+        buf[4] = 2;
+        assert!(Manifold::load_raw(&mut buf.as_slice()).is_err());
+    }
+
+    #[test]
+    fn test_off() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 1., 1.], [3., 3., 3.]);
+        let m = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let mut buf = vec![];
+        m.write_off(&mut buf).unwrap();
+        let r = Manifold::from_off(&mut buf.as_slice()).unwrap();
+        assert_eq!((r.nv, r.nf), (m.nv, m.nf));
+        assert_eq!(r.ps, m.ps);
+
+        // quads with comments, counts on the keyword line and trailing face colors
+        let txt = "OFF 8 6 12 # cube\n0 0 0\n1 0 0\n1 1 0\n0 1 0\n0 0 1\n1 0 1\n1 1 1\n0 1 1\n\
+            4 0 3 2 1 255 0 0\n4 4 5 6 7\n4 0 1 5 4\n4 1 2 6 5\n4 2 3 7 6\n4 3 0 4 7\n";
+        let c = Manifold::from_off(&mut txt.as_bytes()).unwrap();
+        assert_eq!((c.nv, c.nf), (8, 12));
+        assert!((volume(&c) - 1.).abs() < 1e-5);
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod test_serde {
+    use crate::prelude::*;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_serde() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 1., 1.], [3., 3., 3.]);
+        let m = compute_boolean(&a, &b, OpType::Intersect).unwrap();
+        let s = serde_json::to_string(&m).unwrap();
+        assert!(s.starts_with(r#"{"ps":"#) && !s.contains("collider"));
+
+        let r: Manifold = serde_json::from_str(&s).unwrap();
+        assert_eq!((r.ps.clone(), r.eps, r.tol), (m.ps.clone(), m.eps, m.tol));
+        assert_eq!(r.face_normals, m.face_normals);
+        assert_eq!(r.coplanar, m.coplanar);
+        assert!(compute_boolean(&r, &a, OpType::Add).is_ok());
+
+        let op: OpType = serde_json::from_str(&serde_json::to_string(&OpType::Subtract).unwrap()).unwrap();
+        assert_eq!(op, OpType::Subtract);
+        assert!(serde_json::from_str::<Manifold>(r#"{"ps":[[0,0,0]],"tris":[[0,1,2]],"eps":0,"tol":0}"#).is_err());
+    }
+}
+
+#[cfg(test)]
+mod test_buffers {
+    use crate::prelude::*;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_buffers() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 1., 1.], [3., 3., 3.]);
+        let m = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let ts = m.triangles();
+        assert_eq!(ts.len(), m.nf);
+        assert_eq!(m.positions_f64().len(), m.nv);
+
+        let sb = m.to_buffers::<u32>(None).unwrap();
+        assert_eq!((sb.positions.len(), sb.normals.len()), (m.nv * 3, m.nv * 3));
+        assert_eq!(sb.indices, ts.iter().flatten().map(|&i| i as u32).collect::<Vec<_>>());
+
+        // every corner of the box with the notch is sharp, so each vertex splits into one copy per
+        // incident planar face and all normals are axis aligned
+        let fb = m.to_buffers::<usize>(Some(0.5)).unwrap();
+        assert_eq!(fb.indices.len(), m.nh);
+        assert!(fb.source.len() > m.nv);
+        assert!(fb.normals.chunks(3).all(|n| n.iter().filter(|x| x.abs() > 0.999).count() == 1));
+        for (k, &i) in fb.indices.iter().enumerate() {
+            assert_eq!(fb.source[i], ts[k / 3][k % 3]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_crease {
+    use crate::prelude::*;
+    use crate::{Real, Vec2};
+
+    #[test]
+    fn test_corner_normals() {
+        let n = 32;
+        let poly = (0..n).map(|i| {
+            let a = std::f64::consts::TAU as Real * i as Real / n as Real;
+            Vec2::new(a.cos(), a.sin())
+        }).collect::<Vec<_>>();
+        let m = Manifold::extrude(&[poly], 1., 0, 0., Vec2::ONE).unwrap();
+
+        let ns = m.corner_normals(0.5);
+        assert_eq!(ns.len(), m.nh);
+        for (c, nc) in ns.iter().enumerate() {
+            let p = m.ps[m.hs[c].tail];
+            let f = m.face_normals[c / 3];
+            if f.z.abs() > 0.5 {
+                // caps keep their plane normal however they are tessellated
+                assert!((nc.z - f.z.signum()).abs() < 1e-5);
+            } else {
+                // the sides are smooth across the polygon edges
+                assert!(nc.z.abs() < 1e-5);
+                assert!(nc.truncate().dot(p.truncate().normalize()) > 0.999);
+            }
+        }
+
+        // every vertex splits into a side and a cap copy
+        let b = m.to_buffers::<u32>(Some(0.5)).unwrap();
+        assert_eq!(b.source.len(), m.nv * 2);
+        let mut buf = vec![];
+        m.write_glb(GltfNormals::Crease(0.5), &mut buf).unwrap();
+        let json = String::from_utf8_lossy(&buf);
+        assert!(json.contains(&format!(r#""count":{},"type":"VEC3","min""#, m.nv * 2)));
+    }
+}
+
+#[cfg(test)]
+mod test_topology {
+    use crate::prelude::*;
+    use super::test_minkowski::gen_box;
+
+    #[test]
+    fn test_topology() {
+        let a = gen_box([0., 0., 0.], [2., 2., 2.]);
+        let b = gen_box([1., 1., 1.], [3., 3., 3.]);
+        let m = compute_boolean(&a, &b, OpType::Subtract).unwrap();
+        let t = m.topology();
+
+        // euler characteristic of a sphere
+        let ne = m.edges().count();
+        assert_eq!(ne * 2, m.nh);
+        assert_eq!(m.nv + m.nf, ne + 2);
+        assert_eq!((0..m.nv).map(|v| t.valence(v)).sum::<usize>(), m.nh);
+
+        for v in 0..m.nv {
+            let star = t.star(v).collect::<Vec<_>>();
+            assert!(star.iter().all(|&h| m.hs[h].tail == v));
+            let ring = t.one_ring(v).collect::<Vec<_>>();
+            let mut uniq = ring.clone();
+            uniq.sort();
+            uniq.dedup();
+            assert_eq!(uniq.len(), ring.len());
+            assert!(t.vertex_faces(v).zip(star.iter()).all(|(f, &h)| f == h / 3));
+        }
+        for f in 0..m.nf {
+            for g in m.face_neighbors(f) { assert!(m.face_neighbors(g).contains(&f)); }
+        }
+
+        // the star turns clockwise seen from outside at a convex corner
+        let v = m.ps.iter().position(|p| p.x == 0. && p.y == 0. && p.z == 0.).unwrap();
+        let hs = t.star(v).collect::<Vec<_>>();
+        let d = |h: usize| m.ps[m.hs[h].head] - m.ps[v];
+        assert!(d(hs[0]).cross(d(hs[1])).dot(m.ps[v] - crate::Vec3::ONE) < 0.);
+    }
+}
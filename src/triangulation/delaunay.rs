@@ -0,0 +1,50 @@
+//--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
+//--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
+
+use std::collections::HashMap;
+use crate::{is_ccw_2d, Real, Vec2, Vec3u, K_PRECISION};
+
+// Positive when d lies inside the circumcircle of the ccw triangle abc,
+// with a margin relative to the magnitude of the terms to avoid flipping back and forth.
+fn in_circle(a: Vec2, b: Vec2, c: Vec2, d: Vec2) -> bool {
+    let (a, b, c) = (a - d, b - d, c - d);
+    let t0 = a.length_squared() * b.perp_dot(c);
+    let t1 = b.length_squared() * c.perp_dot(a);
+    let t2 = c.length_squared() * a.perp_dot(b);
+    t0 + t1 + t2 > K_PRECISION * (t0.abs() + t1.abs() + t2.abs())
+}
+
+/// Lawson edge flips toward the constrained Delaunay triangulation. Edges without a twin,
+/// i.e. the polygon boundary, are constraints and never flipped. A flip is only taken
+/// when both new triangles are ccw beyond `eps`, so the covered region is preserved.
+pub fn delaunay_flip<F>(tris: &mut [Vec3u], pos: F, eps: Real) where F: Fn(usize) -> Vec2 {
+    let mut e2t = HashMap::new();
+    for (i, t) in tris.iter().enumerate() {
+        for j in 0..3 { e2t.insert((t[j], t[(j + 1) % 3]), i); }
+    }
+
+    let mut stack = e2t.keys().filter(|(a, b)| a < b).copied().collect::<Vec<_>>();
+    stack.sort();
+    let mut budget = tris.len() * tris.len() + 16;
+
+    while let Some((a, b)) = stack.pop() {
+        let (Some(&t0), Some(&t1)) = (e2t.get(&(a, b)), e2t.get(&(b, a))) else { continue; };
+        let c = (0..3).map(|j| tris[t0][j]).find(|&v| v != a && v != b).unwrap();
+        let d = (0..3).map(|j| tris[t1][j]).find(|&v| v != a && v != b).unwrap();
+        let (pa, pb, pc, pd) = (pos(a), pos(b), pos(c), pos(d));
+        if !in_circle(pa, pb, pc, pd) { continue; }
+        if is_ccw_2d(&pa, &pd, &pc, eps) <= 0 || is_ccw_2d(&pd, &pb, &pc, eps) <= 0 { continue; }
+        if budget == 0 { break; }
+        budget -= 1;
+
+        for t in [t0, t1] {
+            for j in 0..3 { e2t.remove(&(tris[t][j], tris[t][(j + 1) % 3])); }
+        }
+        tris[t0] = Vec3u::new(a, d, c);
+        tris[t1] = Vec3u::new(d, b, c);
+        for t in [t0, t1] {
+            for j in 0..3 { e2t.insert((tris[t][j], tris[t][(j + 1) % 3]), t); }
+        }
+        stack.extend([(a, d), (d, b), (b, c), (c, a)].map(|(u, v)| (u.min(v), u.max(v))));
+    }
+}
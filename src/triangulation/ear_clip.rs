@@ -1,99 +1,192 @@
 //--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
 //--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
 
-use std::cell::RefCell;
 use std::cmp::{Ordering, PartialEq};
 use std::collections::BTreeSet;
-use std::rc::{Rc, Weak};
 use crate::{det2x2, is_ccw_2d, safe_normalize, K_BEST, K_PRECISION, Real, Vec2, Vec3u};
 use super::flat_tree::{compute_flat_tree, compute_query_flat_tree, Rect};
 use crate::triangulation::Pt;
 
+// Verts live in an arena and link to their neighbors by index.
 #[derive(Clone, Debug)]
 pub struct Ecvt {
-    pub idx: usize,                       // vert idx
-    pub pos: Vec2,                        // vert pos
-    pub dir: Vec2,                        // right dir
-    pub ear: Option<Weak<RefCell<Ecvt>>>, // itr to self, just needed for quick removal from the ear queue
-    pub vl:  Option<Weak<RefCell<Ecvt>>>,
-    pub vr:  Option<Weak<RefCell<Ecvt>>>,
+    pub idx: usize,  // vert idx
+    pub pos: Vec2,   // vert pos
+    pub dir: Vec2,   // right dir
+    pub ear: bool,   // whether it was queued as an ear, just needed for quick removal from the queue
+    pub vl:  usize,
+    pub vr:  usize,
     pub cost: Real,
 }
 
 impl Ecvt {
-    pub fn ptr_l(&self) -> EvPtr { self.vl.as_ref().unwrap().upgrade().unwrap() }
-    pub fn ptr_r(&self) -> EvPtr { self.vr.as_ref().unwrap().upgrade().unwrap() }
-    pub fn idx_l(&self) -> usize { self.ptr_l().borrow().idx }
-    pub fn idx_r(&self) -> usize { self.ptr_r().borrow().idx }
-    pub fn pos_l(&self) -> Vec2 { self.ptr_l().borrow().pos }
-    pub fn pos_r(&self) -> Vec2 { self.ptr_r().borrow().pos }
-    pub fn dir_l(&self) -> Vec2 { self.ptr_l().borrow().dir }
-    pub fn dir_r(&self) -> Vec2 { self.ptr_r().borrow().dir }
-    pub fn ptr_l_of_r(&self) -> EvPtr { self.ptr_r().borrow().ptr_l() }
-    pub fn ptr_r_of_l(&self) -> EvPtr { self.ptr_l().borrow().ptr_r() }
-
     pub fn new(idx: usize, pos: Vec2) -> Self {
-        Self { idx, pos, dir: Vec2::new(0., 0.), ear: None, vl: None, vr: None, cost: 0. }
+        Self { idx, pos, dir: Vec2::new(0., 0.), ear: false, vl: usize::MAX, vr: usize::MAX, cost: 0. }
+    }
+}
+
+// For verts outside the ear, apply a cost based on the Delaunay condition
+// to aid in prioritization and produce cleaner triangulations. This doesn't
+// affect robustness but may be adjusted to improve output.
+fn delaunay_cost(diff: &Vec2, scl: Real, eps: Real) -> Real {
+    -eps - scl * diff.length_squared()
+}
+
+#[derive(Clone, Copy)] struct EvMinCost(Real, usize);
+#[derive(Clone)] struct EvMaxPosX(Real, usize, Rect);
+
+impl Eq for EvMinCost {}
+impl PartialEq for EvMinCost {
+    fn eq(&self, other: &Self) -> bool { self.0 == other.0 && self.1 == other.1 }
+}
+impl PartialOrd for EvMinCost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+impl Ord for EvMinCost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.partial_cmp(&other.0).unwrap_or(Ordering::Equal).then_with(|| self.1.cmp(&other.1))
+    }
+}
+
+impl Eq for EvMaxPosX {}
+impl PartialEq for EvMaxPosX {
+    fn eq(&self, other: &Self) -> bool { self.0 == other.0 && self.1 == other.1 }
+}
+impl PartialOrd for EvMaxPosX {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
+}
+impl Ord for EvMaxPosX {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal).then_with(|| self.1.cmp(&other.1))
     }
+}
+
+/*
+ * Ear-clipping triangulator based on David Eberly's approach from Geometric
+ * Tools, but adjusted to handle epsilon-valid polygons, and including a
+ * fallback that ensures manifold triangulation even for overlapping polygons.
+ * This is reduced from an O(n^2) algorithm by means of our BVH Collider.
+ *
+ * The main adjustments for robustness involve clipping the sharpest ears first
+ * (a known technique to get higher triangle quality), and doing an exhaustive
+ * search to determine ear convexity exactly if the first geometric result is
+ * within epsilon.
+ */
+pub struct IdxCollider {
+    pub pts: Vec<Pt>,
+    pub rfs: Vec<usize>,
+}
+
+pub struct EarClip {
+    polygon: Vec<Ecvt>,
+    simples: Vec<usize>, // contour + recursive ccw loops
+    contour: Vec<usize>,
+    queue: BTreeSet<EvMinCost>,
+    holes: BTreeSet<EvMaxPosX>,
+    tris: Vec<Vec3u>,
+    bbox: Rect,
+    eps: Real
+}
+
+impl EarClip {
+    pub fn new(polys: &[Vec<Pt>], eps: Real) -> Self {
+        let mut clip = Self {
+            polygon: vec![],
+            simples: vec![],
+            contour: vec![],
+            queue: BTreeSet::new(),
+            holes: BTreeSet::new(),
+            tris: vec![],
+            bbox: Rect::default(),
+            eps,
+        };
+
+        let mut inits = clip.initialize(polys);
+
+        for v in 0..clip.polygon.len() { clip.clip_degenerate(v); }
+        for v in inits.iter_mut() { clip.find_start(v); }
+
+        clip
+    }
+
+    pub fn triangulate(&mut self) -> Vec<Vec3u> {
+        let vs = self.holes.iter().cloned().collect::<Vec<_>>();
+        for v in vs { self.cut_key_hole(&v); }
+        let vs = self.simples.clone();
+        for mut v in vs { self.triangulate_poly(&mut v); }
+        std::mem::take(&mut self.tris)
+    }
+
+    fn idx_l(&self, v: usize) -> usize { self.polygon[self.polygon[v].vl].idx }
+    fn idx_r(&self, v: usize) -> usize { self.polygon[self.polygon[v].vr].idx }
+    fn pos_l(&self, v: usize) -> Vec2 { self.polygon[self.polygon[v].vl].pos }
+    fn pos_r(&self, v: usize) -> Vec2 { self.polygon[self.polygon[v].vr].pos }
+    fn dir_l(&self, v: usize) -> Vec2 { self.polygon[self.polygon[v].vl].dir }
+    fn l_of_r(&self, v: usize) -> usize { self.polygon[self.polygon[v].vr].vl }
+    fn r_of_l(&self, v: usize) -> usize { self.polygon[self.polygon[v].vl].vr }
+
+    // When an ear vert is clipped, its neighbors get linked, so they get unlinked
+    // from it, but it is still linked to them.
+    fn clipped(&self, v: usize) -> bool { self.l_of_r(v) != v }
+    fn folded (&self, v: usize) -> bool { self.polygon[v].vl == self.polygon[v].vr }
 
     // Shorter than half of epsilon, to be conservative so that it doesn't
     // cause CW triangles that exceed epsilon due to rounding error.
-    pub fn is_short(&self, eps: Real) -> bool {
-        (self.pos_r() - self.pos).length_squared() * 4. < eps.powi(2)
+    fn is_short(&self, v: usize, eps: Real) -> bool {
+        (self.pos_r(v) - self.polygon[v].pos).length_squared() * 4. < eps.powi(2)
     }
 
     // Returns true if Vert is on inside the edge that goes from tail to tail->right.
     // This will walk the edges if necessary until a clear answer is found (beyond epsilon).
     // If toLeft is true, this Vert will walk its edges to the left. This should be chosen
     // so that the edges walk in the same general direction - tail always walks to the right.
-    pub fn inside_edge(&self, pair: &EvPtr, eps: Real, to_left: bool) -> bool {
-        let mut nl = self.ptr_r_of_l();     // next left
-        let mut nr = pair.borrow().ptr_r(); // next right
-        let mut ct = Rc::clone(pair);       // center
-        let mut lt = Rc::clone(pair);       // last
-
-        while !Rc::ptr_eq(&nl, &nr) &&
-              !Rc::ptr_eq(pair, &nr) &&
-              !Rc::ptr_eq(&nl, &(if to_left { self.ptr_r() } else { self.ptr_l() }))
-        {
-            let l2 = (nl.borrow().pos - ct.borrow().pos).length_squared();
-            let r2 = (nr.borrow().pos - ct.borrow().pos).length_squared();
+    fn inside_edge(&self, v: usize, pair: usize, eps: Real, to_left: bool) -> bool {
+        let vs = &self.polygon;
+        let mut nl = self.r_of_l(v); // next left
+        let mut nr = vs[pair].vr;    // next right
+        let mut ct = pair;           // center
+        let mut lt = pair;           // last
+        let step_l = |i: usize| if to_left { vs[i].vl } else { vs[i].vr };
+
+        while nl != nr && pair != nr && nl != (if to_left { vs[v].vr } else { vs[v].vl }) {
+            let l2 = (vs[nl].pos - vs[ct].pos).length_squared();
+            let r2 = (vs[nr].pos - vs[ct].pos).length_squared();
 
             if l2 <= eps.powi(2) {
-                nl = if to_left { nl.borrow().ptr_l() } else { nl.borrow().ptr_r() };
+                nl = step_l(nl);
                 continue;
             }
 
             if r2 <= eps.powi(2) {
-                nr = { nr.borrow().ptr_r() };
+                nr = vs[nr].vr;
                 continue;
             }
 
-            let e = nr.borrow().pos - nl.borrow().pos;
+            let e = vs[nr].pos - vs[nl].pos;
             if e.length_squared() <= eps.powi(2) {
-                lt = Rc::clone(&ct);
-                ct = Rc::clone(&nl);
-                nl = if to_left { nl.borrow().ptr_l() } else { nl.borrow().ptr_r() };
-                if Rc::ptr_eq(&nl, &nr) { break; }
-                nr = { nr.borrow().ptr_r() };
+                lt = ct;
+                ct = nl;
+                nl = step_l(nl);
+                if nl == nr { break; }
+                nr = vs[nr].vr;
                 continue;
             }
 
-            let mut convex = is_ccw_2d(&nl.borrow().pos, &ct.borrow().pos, &nr.borrow().pos, eps);
-            if !Rc::ptr_eq(&ct, &lt) {
-                convex += is_ccw_2d(&lt.borrow().pos, &ct.borrow().pos, &nl.borrow().pos, eps)
-                        + is_ccw_2d(&nr.borrow().pos, &ct.borrow().pos, &lt.borrow().pos, eps);
+            let mut convex = is_ccw_2d(&vs[nl].pos, &vs[ct].pos, &vs[nr].pos, eps);
+            if ct != lt {
+                convex += is_ccw_2d(&vs[lt].pos, &vs[ct].pos, &vs[nl].pos, eps)
+                        + is_ccw_2d(&vs[nr].pos, &vs[ct].pos, &vs[lt].pos, eps);
             }
             if convex != 0 { return convex > 0; }
 
             if l2 < r2 {
-                ct = Rc::clone(&nl);
-                nl = if to_left { nl.borrow().ptr_l() } else { nl.borrow().ptr_r() };
+                ct = nl;
+                nl = step_l(nl);
             } else {
-                ct = Rc::clone(&nr);
-                nr = { nr.borrow().ptr_r() };
+                ct = nr;
+                nr = vs[nr].vr;
             }
-            lt = Rc::clone(&ct);
+            lt = ct;
         }
 
         // The whole polygon is degenerate - consider this to be convex.
@@ -101,33 +194,35 @@ impl Ecvt {
     }
 
     // Returns true for convex or collinear ears.
-    pub fn is_convex(&self, eps: Real) -> bool {
-        is_ccw_2d(&self.pos_l(), &self.pos, &self.pos_r(), eps) >= 0
+    fn is_convex(&self, v: usize, eps: Real) -> bool {
+        is_ccw_2d(&self.pos_l(v), &self.polygon[v].pos, &self.pos_r(v), eps) >= 0
     }
 
     // Subtly different from !IsConvex because IsConvex will return true for collinear
     // non-folded verts, while IsReflex will always check until actual certainty is determined.
-    pub fn is_reflex(&self, eps: Real) -> bool {
-        !self.ptr_l().borrow().inside_edge(&self.ptr_r_of_l(), eps, true)
+    fn is_reflex(&self, v: usize, eps: Real) -> bool {
+        !self.inside_edge(self.polygon[v].vl, self.r_of_l(v), eps, true)
     }
 
     // Returns the x-value on this edge corresponding to the start.y value,
     // returning NAN if the edge does not cross the value from below to above,
     // right of start - all within an epsilon tolerance. If onTop != 0,
     // this restricts which end is allowed to terminate within the epsilon band.
-    pub fn interpolate_y2x(&self, bgn: &Vec2, on_top: i32, eps: Real) -> Option<Real> {
-        if (self.pos.y - bgn.y).abs() <= eps {
-            if self.pos_r().y <= bgn.y + eps || on_top == 1 { return None; }
-            return Some(self.pos.x);
+    fn interpolate_y2x(&self, v: usize, bgn: &Vec2, on_top: i32, eps: Real) -> Option<Real> {
+        let p = self.polygon[v].pos;
+        let pr = self.pos_r(v);
+        if (p.y - bgn.y).abs() <= eps {
+            if pr.y <= bgn.y + eps || on_top == 1 { return None; }
+            return Some(p.x);
         }
-        if self.pos.y < bgn.y - eps {
-            if self.pos_r().y > bgn.y + eps {
-                let aspect = (self.pos_r().x - self.pos.x) / (self.pos_r().y - self.pos.y);
-                return Some(self.pos.x + (bgn.y - self.pos.y) * aspect);
+        if p.y < bgn.y - eps {
+            if pr.y > bgn.y + eps {
+                let aspect = (pr.x - p.x) / (pr.y - p.y);
+                return Some(p.x + (bgn.y - p.y) * aspect);
             }
 
-            if self.pos_r().y < bgn.y - eps || on_top == -1 { return None; }
-            return Some(self.pos_r().x);
+            if pr.y < bgn.y - eps || on_top == -1 { return None; }
+            return Some(pr.x);
         }
         None
     }
@@ -135,11 +230,12 @@ impl Ecvt {
     // This finds the cost of this vert relative to one of the two closed sides of the ear.
     // Points are valid even when they touch, so long as their edge goes to the outside.
     // No need to check the other side, since all verts are processed in the EarCost loop.
-    pub fn signed_dist(&self, pair: &Ecvt, unit: Vec2, eps: Real) -> Real {
-        let d = det2x2(&unit, &(pair.pos - self.pos));
+    fn signed_dist(&self, v: usize, pair: usize, unit: Vec2, eps: Real) -> Real {
+        let p = self.polygon[v].pos;
+        let d = det2x2(&unit, &(self.polygon[pair].pos - p));
         if d.abs() < eps {
-            let dr = det2x2(&unit, &(pair.pos_r() - self.pos));
-            let dl = det2x2(&unit, &(pair.pos_l() - self.pos));
+            let dr = det2x2(&unit, &(self.pos_r(pair) - p));
+            let dl = det2x2(&unit, &(self.pos_l(pair) - p));
             if dr.abs() > eps { return dr; }
             if dl.abs() > eps { return dl; }
         }
@@ -148,20 +244,13 @@ impl Ecvt {
 
     // Find the cost of Vert v within this ear, where openSide is the unit
     // vector from Verts right to left - passed in for reuse.
-    pub fn cost(&self, pair: &Ecvt, open_side: &Vec2, eps: Real) -> Real {
-        let c0 = self.signed_dist(pair, self.dir, eps);
-        let c1 = self.signed_dist(pair, self.dir_l(), eps);
-        let co = det2x2(open_side, &(pair.pos - self.pos_r()));
+    fn cost(&self, v: usize, pair: usize, open_side: &Vec2, eps: Real) -> Real {
+        let c0 = self.signed_dist(v, pair, self.polygon[v].dir, eps);
+        let c1 = self.signed_dist(v, pair, self.dir_l(v), eps);
+        let co = det2x2(open_side, &(self.polygon[pair].pos - self.pos_r(v)));
         c0.min(c1).min(co)
     }
 
-    // For verts outside the ear, apply a cost based on the Delaunay condition
-    // to aid in prioritization and produce cleaner triangulations. This doesn't
-    // affect robustness but may be adjusted to improve output.
-    pub fn delaunay_cost(&self, diff: &Vec2, scl: Real, eps: Real) -> Real {
-        -eps - scl * diff.length_squared()
-    }
-
     // This is the expensive part of the algorithm, checking this ear against
     // every Vert to ensure none are inside. The Collider brings the total
     // triangulator cost down from O(n^2) to O(nlogn) for most large polygons.
@@ -170,232 +259,109 @@ impl Ecvt {
     // costs are designed to always give values < -epsilon so they will never affect validity.
     // The first totalCost is designed to give priority to sharper angles.
     // Any cost < (-1 - epsilon) has satisfied the Delaunay condition.
-    pub fn ear_cost(&self, eps: Real, collider: &IdxCollider) -> Real {
-        let dif = self.pos_l() - self.pos_r();
+    fn ear_cost(&self, v: usize, eps: Real, collider: &IdxCollider) -> Real {
+        let p = self.polygon[v].pos;
+        let dif = self.pos_l(v) - self.pos_r(v);
         let len = dif.length();
         let scl = if len > eps { 4. / len.powf(2.) } else { Real::MAX };
-        let center = (self.pos_l() + self.pos_r()) * 0.5;
+        let center = (self.pos_l(v) + self.pos_r(v)) * 0.5;
         let radius = len * 0.5;
         let open_side = dif.normalize();
 
-        let mut total = self.dir_l().dot(self.dir) - 1. - eps;
-        if is_ccw_2d(&self.pos, &self.pos_l(), &self.pos_r(), eps) == 0 { return total; }
+        let mut total = self.dir_l(v).dot(self.polygon[v].dir) - 1. - eps;
+        if is_ccw_2d(&p, &self.pos_l(v), &self.pos_r(v), eps) == 0 { return total; }
 
         let mut bb = Rect::new(
             &Vec2::new(center.x - radius, center.y - radius),
             &Vec2::new(center.x + radius, center.y + radius),
         );
-        bb.union(self.pos);
+        bb.union(p);
         bb.min -= Vec2::new(eps, eps);
         bb.max += Vec2::new(eps, eps);
 
-        compute_query_flat_tree(&collider.pts, &bb, |v| {
-            let test = Rc::clone(&collider.rfs[v.idx]);
-            if !clipped(&test) &&
-               test.borrow().idx != self.idx &&
-               test.borrow().idx != self.idx_l() &&
-               test.borrow().idx != self.idx_r()
-            {
-                let mut cost = self.cost(&test.borrow(), &open_side, eps);
+        let (i, il, ir) = (self.polygon[v].idx, self.idx_l(v), self.idx_r(v));
+        compute_query_flat_tree(&collider.pts, &bb, |q| {
+            let test = collider.rfs[q.idx];
+            let ti = self.polygon[test].idx;
+            if !self.clipped(test) && ti != i && ti != il && ti != ir {
+                let mut cost = self.cost(v, test, &open_side, eps);
                 if cost < -eps {
-                    cost = self.delaunay_cost(&(test.borrow().pos - center), scl, eps);
+                    cost = delaunay_cost(&(self.polygon[test].pos - center), scl, eps);
                 }
                 if cost > total { total = cost; }
             }
         });
         total
     }
-}
-
-type EvPtr = Rc<RefCell<Ecvt>>;
 
-// When an ear vert is clipped, its neighbors get linked, so they get unlinked
-// from it, but it is still linked to them.
-fn clipped(v: &EvPtr) -> bool { !Rc::ptr_eq(&v.borrow().ptr_l_of_r(), v) }
-fn folded (v: &EvPtr) -> bool { Rc::ptr_eq(&v.borrow().ptr_l(), &v.borrow().ptr_r()) }
-
-impl PartialEq  for Ecvt { fn eq(&self, other: &Self) -> bool { self.cost == other.cost } }
-impl PartialOrd for Ecvt { fn partial_cmp(&self, other: &Self) -> Option<Ordering> { self.cost.partial_cmp(&other.cost) } }
-
-#[derive(Clone)] struct EvPtrMinCost(EvPtr);
-#[derive(Clone)] struct EvPtrMaxPosX(EvPtr, Rect);
-
-impl Eq for EvPtrMinCost {}
-impl PartialEq for EvPtrMinCost {
-    fn eq(&self, other: &Self) -> bool {
-        self.0.borrow().cost == other.0.borrow().cost && Rc::ptr_eq(&self.0, &other.0)
-    }
-}
-impl PartialOrd for EvPtrMinCost {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
-}
-impl Ord for EvPtrMinCost {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.0.borrow().cost
-            .partial_cmp(&other.0.borrow().cost)
-            .unwrap_or(Ordering::Equal)
-            .then_with(|| {
-                let ptr1 = Rc::as_ptr(&self.0) as usize;
-                let ptr2 = Rc::as_ptr(&other.0) as usize;
-                ptr1.cmp(&ptr2)
-            })
-    }
-}
-
-impl Eq for EvPtrMaxPosX {}
-impl PartialEq for EvPtrMaxPosX {
-    fn eq(&self, other: &Self) -> bool {
-        self.0.borrow().pos.x == other.0.borrow().pos.x && Rc::ptr_eq(&self.0, &other.0)
-    }
-}
-impl PartialOrd for EvPtrMaxPosX {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> { Some(self.cmp(other)) }
-}
-impl Ord for EvPtrMaxPosX {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.0.borrow().pos.x
-            .partial_cmp(&self.0.borrow().pos.x)
-            .unwrap_or(Ordering::Equal)
-            .then_with(|| {
-                let ptr1 = Rc::as_ptr(&self.0) as usize;
-                let ptr2 = Rc::as_ptr(&other.0) as usize;
-                ptr1.cmp(&ptr2)
-            })
-    }
-}
-
-// Apply `func` to each unclipped vertex in a polygonal circular list starting at `v`.
-fn do_loop<F>(v: &mut EvPtr, mut func: F) -> Option<EvPtr> where F: FnMut(&mut EvPtr) {
-    let mut w = Rc::clone(v);
-    loop {
-        if clipped(&w) {
-            // Update first to an unclipped vert so we will return to it instead of infinite-loop
-            *v = w.borrow().ptr_l_of_r();
-            if !clipped(v) {
-                w = Rc::clone(v);
-                if folded(&w) { return None; }
-                func(&mut w);
+    // Apply `func` to each unclipped vertex in a polygonal circular list starting at `v`.
+    fn do_loop<F>(&mut self, v: &mut usize, mut func: F) -> Option<usize> where F: FnMut(&mut Self, usize) {
+        let mut w = *v;
+        loop {
+            if self.clipped(w) {
+                // Update first to an unclipped vert so we will return to it instead of infinite-loop
+                *v = self.l_of_r(w);
+                if !self.clipped(*v) {
+                    w = *v;
+                    if self.folded(w) { return None; }
+                    func(self, w);
+                }
+            } else {
+                if self.folded(w) { return None; }
+                func(self, w);
             }
-        } else {
-            if folded(&w) { return None; }
-            func(&mut w);
-        }
-
-        w = { w.borrow().ptr_r() };
-        if Rc::ptr_eq(&w, v) { return Some(w); }
-    }
-}
-
-/*
- * Ear-clipping triangulator based on David Eberly's approach from Geometric
- * Tools, but adjusted to handle epsilon-valid polygons, and including a
- * fallback that ensures manifold triangulation even for overlapping polygons.
- * This is reduced from an O(n^2) algorithm by means of our BVH Collider.
- *
- * The main adjustments for robustness involve clipping the sharpest ears first
- * (a known technique to get higher triangle quality), and doing an exhaustive
- * search to determine ear convexity exactly if the first geometric result is
- * within epsilon.
- */
-
-pub struct IdxCollider {
-    pub pts: Vec<Pt>,
-    pub rfs: Vec<EvPtr>,
-}
 
-pub struct EarClip {
-    polygon: Vec<EvPtr>,
-    simples: Vec<EvPtr>, // contour + recursive ccw loops
-    contour: Vec<EvPtr>,
-    queue: BTreeSet<EvPtrMinCost>,
-    holes: BTreeSet<EvPtrMaxPosX>,
-    tris: Vec<Vec3u>,
-    bbox: Rect,
-    eps: Real
-}
-
-impl EarClip {
-    pub fn new(polys: &[Vec<Pt>], eps: Real) -> Self {
-        let mut clip = Self {
-            polygon: vec![],
-            simples: vec![],
-            contour: vec![],
-            queue: BTreeSet::new(),
-            holes: BTreeSet::new(),
-            tris: vec![],
-            bbox: Rect::default(),
-            eps,
-        };
-
-        let mut inits = clip.initialize(polys);
-
-        let keys = clip.polygon.to_vec();
-        for v in keys { clip.clip_degenerate(&v); }
-        for v in inits.iter_mut() { clip.find_start(v); }
-
-        clip
-    }
-
-    pub fn triangulate(&mut self) -> Vec<Vec3u> {
-        let vs = self.holes.iter().cloned().collect::<Vec<_>>();
-        for v in vs { self.cut_key_hole(&v); }
-        let vs = self.simples.iter().map(Rc::clone).collect::<Vec<_>>();
-        for mut v in vs { self.triangulate_poly(&mut v); }
-        std::mem::take(&mut self.tris)
+            w = self.polygon[w].vr;
+            if w == *v { return Some(w); }
+        }
     }
 
     // This function and JoinPolygons are the only functions that affect
     // the circular list data structure. This helps ensure it remains circular.
-    fn link(vl: &EvPtr, vr: &EvPtr) {
-        let mut bl = vl.borrow_mut();
-        let mut br = vr.borrow_mut();
-        bl.vr = Some(Rc::downgrade(vr));
-        br.vl = Some(Rc::downgrade(vl));
-        bl.dir = safe_normalize(br.pos - bl.pos);
+    fn link(&mut self, vl: usize, vr: usize) {
+        self.polygon[vl].vr = vr;
+        self.polygon[vr].vl = vl;
+        self.polygon[vl].dir = safe_normalize(self.polygon[vr].pos - self.polygon[vl].pos);
     }
 
-
-    pub fn clip_ear(&mut self, ear: &EvPtr) {
-        Self::link(&ear.borrow().ptr_l(), &ear.borrow().ptr_r());
-        let i  = ear.borrow().idx;
-        let il = ear.borrow().idx_l();
-        let ir = ear.borrow().idx_r();
+    fn clip_ear(&mut self, ear: usize) {
+        let (vl, vr) = (self.polygon[ear].vl, self.polygon[ear].vr);
+        self.link(vl, vr);
+        let i  = self.polygon[ear].idx;
+        let il = self.polygon[vl].idx;
+        let ir = self.polygon[vr].idx;
         if il != i && ir != i && il != ir { self.tris.push(Vec3u::new(il, i, ir)); }
     }
 
-    fn clip_degenerate(&mut self, ear: &EvPtr) {
-        if clipped(ear) || folded(ear) { return; }
+    fn clip_degenerate(&mut self, ear: usize) {
+        if self.clipped(ear) || self.folded(ear) { return; }
         let eps = self.eps;
-        let eb = ear.borrow();
-        let p  = ear.borrow().pos;
-        let pl = ear.borrow().pos_l();
-        let pr = ear.borrow().pos_r();
-        if eb.is_short(eps) || (is_ccw_2d(&pl, &p, &pr, eps) == 0 && (pl - p).dot(pr - p) > 0.) {
+        let p  = self.polygon[ear].pos;
+        let pl = self.pos_l(ear);
+        let pr = self.pos_r(ear);
+        if self.is_short(ear, eps) || (is_ccw_2d(&pl, &p, &pr, eps) == 0 && (pl - p).dot(pr - p) > 0.) {
             self.clip_ear(ear);
-            self.clip_degenerate(&eb.ptr_l());
-            self.clip_degenerate(&eb.ptr_r());
+            self.clip_degenerate(self.polygon[ear].vl);
+            self.clip_degenerate(self.polygon[ear].vr);
         }
     }
 
-    fn initialize(&mut self, polys: &[Vec<Pt>]) -> Vec<EvPtr> {
+    fn initialize(&mut self, polys: &[Vec<Pt>]) -> Vec<usize> {
         let mut bgns = vec![];
         for poly in polys.iter() {
-            let v = poly.first().unwrap();
-            self.polygon.push(Rc::new(RefCell::new(Ecvt::new(v.idx, v.pos))));
-
-            let first    = Rc::clone(self.polygon.last().unwrap());
-            let mut last = Rc::clone(&first);
-            self.bbox.union(first.borrow().pos);
-            bgns.push(Rc::clone(&first));
-
-            for v in poly.iter().skip(1) {
+            let first = self.polygon.len();
+            let mut last = first;
+            for v in poly.iter() {
                 self.bbox.union(v.pos);
-                self.polygon.push(Rc::new(RefCell::new(Ecvt::new(v.idx, v.pos))));
-                let next = Rc::clone(self.polygon.last().unwrap());
-                Self::link(&last, &next);
-                last = Rc::clone(&next);
+                self.polygon.push(Ecvt::new(v.idx, v.pos));
+                let next = self.polygon.len() - 1;
+                if next != first { self.link(last, next); }
+                last = next;
+            }
+            if last < self.polygon.len() {
+                bgns.push(first);
+                self.link(last, first);
             }
-            Self::link(&last, &first);
         }
 
         if self.eps < 0. { self.eps = self.bbox.scale() * K_PRECISION; }
@@ -406,48 +372,48 @@ impl EarClip {
         bgns
     }
 
-    fn find_start(&mut self, first: &mut EvPtr) {
-        let origin = first.borrow().pos;
-        let mut bgn = Rc::clone(first);
+    fn find_start(&mut self, first: &mut usize) {
+        let origin = self.polygon[*first].pos;
+        let mut bgn = *first;
         let mut max = Real::MIN;
         let mut bbox = Rect::default();
         let mut area = 0.;
         let mut comp = 0.; // For Kahan's summation
 
-        let add_point = |v: &mut EvPtr| {
-            bbox.union(v.borrow().pos);
-            let tmp0 = det2x2(&(v.borrow().pos - origin), &(v.borrow().pos_r() - origin));
+        let add_point = |s: &mut Self, v: usize| {
+            let p = s.polygon[v].pos;
+            bbox.union(p);
+            let tmp0 = det2x2(&(p - origin), &(s.pos_r(v) - origin));
             let tmp1 = area + tmp0;
             comp += (area - tmp1) + tmp0;
             area = tmp1;
-            if v.borrow().pos.x > max {
-                max = v.borrow().pos.x;
-                bgn = Rc::clone(v);
+            if p.x > max {
+                max = p.x;
+                bgn = v;
             }
         };
 
-        if do_loop(first, add_point).is_none() { return; }
+        if self.do_loop(first, add_point).is_none() { return; }
         area += comp;
         let size = bbox.size();
         let min_area = self.eps * size.x.max(size.y);
 
         if max.is_finite() && area < -min_area {
-            self.holes.insert(EvPtrMaxPosX(Rc::clone(&bgn), bbox));
+            self.holes.insert(EvMaxPosX(self.polygon[bgn].pos.x, bgn, bbox));
         } else {
-            self.simples.push(Rc::clone(&bgn));
-            if area > min_area { self.contour.push(Rc::clone(&bgn));}
+            self.simples.push(bgn);
+            if area > min_area { self.contour.push(bgn); }
         }
     }
 
-
     // Create a collider of all vertices in this polygon, each expanded by epsilon_.
     // Each ear uses this BVH to quickly find a subset of vertices to check for cost.
-    fn vert_collider(start: &mut EvPtr) -> IdxCollider {
+    fn vert_collider(&mut self, start: &mut usize) -> IdxCollider {
         let mut pts = vec![];
         let mut rfs = vec![];
-        do_loop(start, |v| {
-            pts.push(Pt{ pos: v.borrow().pos, idx: rfs.len() });
-            rfs.push(Rc::clone(v));
+        self.do_loop(start, |s, v| {
+            pts.push(Pt{ pos: s.polygon[v].pos, idx: rfs.len() });
+            rfs.push(v);
         });
 
         compute_flat_tree(&mut pts);
@@ -457,140 +423,138 @@ impl EarClip {
     // All holes must be key-holed (attached to an outer polygon) before ear clipping can commerce.
     // Instead of relying on sorting, which may be incorrect due to epsilon,
     // we check for polygon edges both ahead and behind to ensure all valid options are found.
-    fn cut_key_hole(&mut self, bgn: &EvPtrMaxPosX) {
-        let p_bgn = bgn.0.borrow().pos;
+    fn cut_key_hole(&mut self, bgn: &EvMaxPosX) {
+        let b = bgn.1;
+        let p_bgn = self.polygon[b].pos;
         let eps = self.eps;
         let top =
-            if      p_bgn.y >= bgn.1.max.y - eps { 1 }
-            else if p_bgn.y <= bgn.1.min.y + eps { -1 }
+            if      p_bgn.y >= bgn.2.max.y - eps { 1 }
+            else if p_bgn.y <= bgn.2.min.y + eps { -1 }
             else    { 0 };
 
-        let mut con: Option<EvPtr> = None;
+        let mut con: Option<usize> = None;
 
-        for first in self.contour.iter_mut() {
-            do_loop(first, |v| {
-                let vb = v.borrow();
-                if let Some(x) = vb.interpolate_y2x(&p_bgn, top, eps) {
-                    let flag = match &con {
+        for k in 0..self.contour.len() {
+            let mut first = self.contour[k];
+            self.do_loop(&mut first, |s, v| {
+                if let Some(x) = s.interpolate_y2x(v, &p_bgn, top, eps) {
+                    let flag = match con {
                         None => true,
                         Some(c) => {
-                            let cb = c.borrow();
-                            let f1 = is_ccw_2d(&Vec2::new(x, p_bgn.y), &cb.pos, &cb.pos_r(), eps) == 1;
-                            let f2 = if cb.pos.y < vb.pos.y {  vb.inside_edge(c, eps, false) }
-                                                       else { !cb.inside_edge(v, eps, false) };
+                            let pc = s.polygon[c].pos;
+                            let f1 = is_ccw_2d(&Vec2::new(x, p_bgn.y), &pc, &s.pos_r(c), eps) == 1;
+                            let f2 = if pc.y < s.polygon[v].pos.y {  s.inside_edge(v, c, eps, false) }
+                                                                else { !s.inside_edge(c, v, eps, false) };
                             f1 || f2
                         }
                     };
-                    if bgn.0.borrow().inside_edge(v, eps, true) && flag { con = Some(Rc::clone(v)); }
+                    if s.inside_edge(b, v, eps, true) && flag { con = Some(v); }
                 }
             });
+            self.contour[k] = first;
         }
 
         match con {
-            None => { self.simples.push(Rc::clone(&bgn.0)); },
+            None => { self.simples.push(b); },
             Some(c) => {
-                let p = self.find_closer_bridge(&bgn.0, &c);
-                self.join_polygons(&bgn.0, &p);
+                let p = self.find_closer_bridge(b, c);
+                self.join_polygons(b, p);
             }
         }
     }
 
-    fn find_closer_bridge(
-        &mut self,
-        bgn: &EvPtr,
-        end: &EvPtr,
-    ) -> EvPtr {
-        let eb = end.borrow();
-        let p_end = end.borrow().pos;
-        let p_bgn = bgn.borrow().pos;
+    fn find_closer_bridge(&mut self, bgn: usize, end: usize) -> usize {
+        let p_end = self.polygon[end].pos;
+        let p_bgn = self.polygon[bgn].pos;
+        let pr = self.pos_r(end);
         let mut con =
-            if p_end.x < p_bgn.x { eb.ptr_r() }
-            else if eb.pos_r().x < p_bgn.x { Rc::clone(end) }
-            else if eb.pos_r().y - p_bgn.y > p_bgn.y - p_end.y { Rc::clone(end) }
-            else { eb.ptr_r() };
-
-        if (con.borrow().pos.y - p_bgn.y).abs() <= self.eps { return Rc::clone(&con); }
-
-        let above = if con.borrow().pos.y > p_bgn.y { 1. } else { -1. };
-
-        for first in self.contour.iter_mut() {
-            do_loop(first, |v| {
-                let vb = v.borrow();
-                let vp = v.borrow().pos;
-                let inside = above as i32 * is_ccw_2d(&p_bgn, &vp, &con.borrow().pos, self.eps);
-                let f1 = vp.x > p_bgn.x - self.eps;
-                let f2 = vp.y * above > p_bgn.y *above - self.eps;
-                let f3 = inside == 0 && vp.x < con.borrow().pos.x && vp.y * above < con.borrow().pos.y * above;
-                let f4 = vb.inside_edge(end, self.eps, true);
-                let f5 = vb.is_reflex(self.eps);
-                if f1 && f2 && (inside > 0 || f3) && f4 && f5 { con = Rc::clone(v); };
+            if p_end.x < p_bgn.x { self.polygon[end].vr }
+            else if pr.x < p_bgn.x { end }
+            else if pr.y - p_bgn.y > p_bgn.y - p_end.y { end }
+            else { self.polygon[end].vr };
+
+        if (self.polygon[con].pos.y - p_bgn.y).abs() <= self.eps { return con; }
+
+        let above = if self.polygon[con].pos.y > p_bgn.y { 1. } else { -1. };
+
+        for k in 0..self.contour.len() {
+            let mut first = self.contour[k];
+            self.do_loop(&mut first, |s, v| {
+                let vp = s.polygon[v].pos;
+                let cp = s.polygon[con].pos;
+                let inside = above as i32 * is_ccw_2d(&p_bgn, &vp, &cp, s.eps);
+                let f1 = vp.x > p_bgn.x - s.eps;
+                let f2 = vp.y * above > p_bgn.y *above - s.eps;
+                let f3 = inside == 0 && vp.x < cp.x && vp.y * above < cp.y * above;
+                let f4 = s.inside_edge(v, end, s.eps, true);
+                let f5 = s.is_reflex(v, s.eps);
+                if f1 && f2 && (inside > 0 || f3) && f4 && f5 { con = v; };
             });
+            self.contour[k] = first;
         }
-        Rc::clone(&con)
+        con
     }
 
     // Creates a keyhole between the start vert of a hole and the connector vert of an outer polygon.
     // To do this, both verts are duplicated and reattached. This process may create degenerate ears,
     // so these are clipped if necessary to keep from confusing sub_sequent key-holing operations.
-    fn join_polygons(&mut self, sta: &EvPtr, con: &EvPtr) {
-        let sta1 = Rc::new(RefCell::new(sta.borrow().clone()));
-        let con1 = Rc::new(RefCell::new(con.borrow().clone()));
-        self.polygon.push(Rc::clone(&sta1));
-        self.polygon.push(Rc::clone(&con1));
-        sta.borrow().ptr_r().borrow_mut().vl = Some(Rc::downgrade(&sta1));
-        con.borrow().ptr_l().borrow_mut().vr = Some(Rc::downgrade(&con1));
-        Self::link(sta, con);
-        Self::link(&con1, &sta1);
+    fn join_polygons(&mut self, sta: usize, con: usize) {
+        let sta1 = self.polygon.len();
+        let con1 = sta1 + 1;
+        self.polygon.push(self.polygon[sta].clone());
+        self.polygon.push(self.polygon[con].clone());
+        let (sr, cl) = (self.polygon[sta].vr, self.polygon[con].vl);
+        self.polygon[sr].vl = sta1;
+        self.polygon[cl].vr = con1;
+        self.link(sta, con);
+        self.link(con1, sta1);
         self.clip_degenerate(sta);
-        self.clip_degenerate(&sta1);
+        self.clip_degenerate(sta1);
         self.clip_degenerate(con);
-        self.clip_degenerate(&con1);
+        self.clip_degenerate(con1);
     }
 
     // Recalculate the cost of the Vert v ear,
     // updating it in the queue by removing and reinserting it.
-    fn process_ear(&mut self, v: &mut EvPtr, collider: &IdxCollider) {
-        let taken = { let mut b = v.borrow_mut(); b.ear.take() };
-        if let Some(e) = taken {
-            self.queue.remove(&EvPtrMinCost(e.upgrade().unwrap()));
+    fn process_ear(&mut self, v: usize, collider: &IdxCollider) {
+        if self.polygon[v].ear {
+            self.polygon[v].ear = false;
+            self.queue.remove(&EvMinCost(self.polygon[v].cost, v));
         }
 
-        if v.borrow().is_short(self.eps) {
-            v.borrow_mut().cost = K_BEST;
-            let ptr = EvPtrMinCost(Rc::clone(v));
-            v.borrow_mut().ear = Some(Rc::downgrade(&ptr.0));
-            self.queue.insert(ptr);
+        if self.is_short(v, self.eps) {
+            self.polygon[v].cost = K_BEST;
+            self.polygon[v].ear = true;
+            self.queue.insert(EvMinCost(K_BEST, v));
             return;
         }
-        if v.borrow().is_convex(2. * self.eps) {
-            v.borrow_mut().cost = { v.borrow().ear_cost(self.eps, collider) };
-            let ptr = EvPtrMinCost(Rc::clone(v));
-            v.borrow_mut().ear = Some(Rc::downgrade(&ptr.0));
-            self.queue.insert(ptr);
+        if self.is_convex(v, 2. * self.eps) {
+            let cost = self.ear_cost(v, self.eps, collider);
+            self.polygon[v].cost = cost;
+            self.polygon[v].ear = true;
+            self.queue.insert(EvMinCost(cost, v));
             return;
         }
 
-        v.borrow_mut().cost = 1.; // not used, but marks reflex verts for debug
+        self.polygon[v].cost = 1.; // not used, but marks reflex verts for debug
     }
 
-    pub fn triangulate_poly(&mut self, first: &mut EvPtr) {
-        let c = Self::vert_collider(first);
+    fn triangulate_poly(&mut self, first: &mut usize) {
+        let c = self.vert_collider(first);
         if c.rfs.is_empty() { return; }
 
         let mut nt = -2;
         self.queue.clear();
 
-        if let Some(mut v) = do_loop(first, |v| { self.process_ear(v, &c); nt += 1; }) {
+        if let Some(mut v) = self.do_loop(first, |s, v| { s.process_ear(v, &c); nt += 1; }) {
             while nt > 0 {
-                if let Some(q) = self.queue.pop_first() { v = Rc::clone(&q.0); }
-                self.clip_ear(&v);
+                if let Some(q) = self.queue.pop_first() { v = q.1; }
+                self.clip_ear(v);
                 nt -= 1;
-                self.process_ear(&mut v.borrow().ptr_l(), &c);
-                self.process_ear(&mut v.borrow().ptr_r(), &c);
-                let ptr_r = v.borrow().ptr_r();
-                v = Rc::clone(&ptr_r);
+                self.process_ear(self.polygon[v].vl, &c);
+                self.process_ear(self.polygon[v].vr, &c);
+                v = self.polygon[v].vr;
             }
         }
     }
 }
-
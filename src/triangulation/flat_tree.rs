@@ -29,13 +29,11 @@ pub fn compute_query_flat_tree<F>(
     rect: &Rect,
     mut func: F,
 ) where F: FnMut(&Pt) {
-    for p in pts.iter() { if rect.contains(&p.pos) { func(p);} }
-
-    //if pts.len() <= 8 {
-    //    for p in pts.iter() { if rect.contains(&p.pos) { func(p);} }
-    //} else {
-    //    query_two_d_tree(pts, rect.clone(), func);
-    //}
+    if pts.len() <= 8 {
+        for p in pts.iter() { if rect.contains(&p.pos) { func(p);} }
+    } else {
+        query_two_d_tree(pts, rect.clone(), func);
+    }
 }
 
 pub fn query_two_d_tree<F>(pts: &[Pt], r: Rect, mut f: F) where F: FnMut(&Pt) {
@@ -93,12 +91,17 @@ pub fn query_two_d_tree<F>(pts: &[Pt], r: Rect, mut f: F) where F: FnMut(&Pt) {
             cur = rect_l;
             len = mid_oft;
             lev += 1;
-        } else {
+        } else if overlaps_r {
             cur = rect_r;
             bgn = mid_idx + 1;
             len -= mid_oft + 1;
             lev += 1;
-        }
+        } else if let Some((rc, b, ln, lv)) = stack.pop() {
+            cur = rc;
+            bgn = b;
+            len = ln;
+            lev = lv;
+        } else { break; }
     }
 }
 
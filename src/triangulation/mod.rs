@@ -1,6 +1,7 @@
 //--- Copyright (C) 2025 Saki Komikado <komietty@gmail.com>,
 //--- This Source Code Form is subject to the terms of the Mozilla Public License v.2.0.
 
+pub mod delaunay;
 pub mod ear_clip;
 pub mod flat_tree;
 pub mod tri_halfs;
@@ -8,6 +9,7 @@ pub mod tri_halfs;
 use std::collections::{BTreeMap, VecDeque};
 use crate::boolean45::Boolean45;
 use crate::{Manifold, Vec2, Vec3, Vec3u, Half, Tref, get_aa_proj_matrix, compute_aa_proj, is_ccw_3d, Real};
+use crate::triangulation::delaunay::delaunay_flip;
 use crate::triangulation::ear_clip::EarClip;
 use crate::triangulation::tri_halfs::tri_halfs_single;
 #[cfg(feature = "rayon")] use rayon::prelude::*;
@@ -24,27 +26,28 @@ pub fn triangulate(
     mq: &Manifold,
     b45: &Boolean45,
     eps: Real,
+    delaunay: bool,
 ) -> Result<Triangulation, String> {
 
     #[cfg(feature = "rayon")] {
         let (mut ts, mut rs, ns) = (0..b45.hid_per_f.len() - 1)
             .into_par_iter()
-            .map(|fid| {
+            .map(|fid| -> Result<_, String> {
                 let hid = b45.hid_per_f[fid] as usize;
-                let ts_ = process_face(&b45, fid, eps);
+                let ts_ = process_face(&b45, fid, eps, delaunay)?;
                 let rs_ = vec![b45.rs[hid].clone(); ts_.len()];
                 let ns_ = vec![b45.ns[fid].clone(); ts_.len()];
-                (ts_, rs_, ns_)
+                Ok((ts_, rs_, ns_))
             })
-            .reduce(
+            .try_reduce(
                 || (vec![], vec![], vec![]),
                 |mut acc, (mut ts_, mut rs_, mut ns_)| {
                     acc.0.append(&mut ts_);
                     acc.1.append(&mut rs_);
                     acc.2.append(&mut ns_);
-                    acc
+                    Ok(acc)
                 },
-            );
+            )?;
         update_reference(mp, mq, &mut rs);
         Ok(Triangulation { hs: tri_halfs_multi(&mut ts), ns, rs })
     }
@@ -56,7 +59,7 @@ pub fn triangulate(
 
         for fid in 0..b45.hid_per_f.len() - 1 {
             let hid = b45.hid_per_f[fid] as usize;
-            let t = process_face(b45, fid, eps);
+            let t = process_face(b45, fid, eps, delaunay)?;
             let r = b45.rs[hid];
             let n = b45.ns[fid];
             rs.extend(vec![r; t.len()]);
@@ -72,14 +75,15 @@ pub fn triangulate(
 fn process_face(
     b45: &Boolean45,
     fid: usize,
-    eps: Real
-) -> Vec<Vec3u> {
+    eps: Real,
+    delaunay: bool,
+) -> Result<Vec<Vec3u>, String> {
     let e0 = b45.hid_per_f[fid] as usize;
     let e1 = b45.hid_per_f[fid + 1] as usize;
     match e1 - e0 {
-        3 =>  single_triangulate(b45, e0),
-        4 =>  square_triangulate(b45, fid, eps),
-        _ => general_triangulate(b45, fid, eps),
+        3 => Ok(single_triangulate(b45, e0)),
+        4 => Ok(square_triangulate(b45, fid, eps)),
+        _ => general_triangulate(b45, fid, eps, delaunay),
     }
 }
 
@@ -171,8 +175,9 @@ fn square_triangulate(
 fn general_triangulate(
     b45: &Boolean45,
     fid: usize,
-    eps: Real
-) -> Vec<Vec3u> {
+    eps: Real,
+    delaunay: bool,
+) -> Result<Vec<Vec3u>, String> {
     let proj  = get_aa_proj_matrix(&b45.ns[fid]);
     let loops = assemble_halfs(&b45.hs, &b45.hid_per_f, fid);
     let polys = loops.iter().map(|poly|
@@ -183,11 +188,38 @@ fn general_triangulate(
         }).collect()
     ).collect::<Vec<Vec<_>>>();
 
-    EarClip::new(&polys, eps).triangulate().iter().map(|t| Vec3u::new(
-        b45.hs[t.x].tail,
-        b45.hs[t.y].tail,
-        b45.hs[t.z].tail
-    )).collect()
+    let pos = |e: usize| b45.ps[b45.hs[e].tail];
+    match triangulate_checked(&polys, pos, &b45.ns[fid], eps, delaunay) {
+        Some(tris) => Ok(tris.iter().map(|t| Vec3u::new(
+            b45.hs[t.x].tail,
+            b45.hs[t.y].tail,
+            b45.hs[t.z].tail
+        )).collect()),
+        None => {
+            let ps = polys.iter().map(|p| p.iter().map(|v| v.pos).collect()).collect::<Vec<_>>();
+            Err(format!("triangulation failed on face {fid} with {} loops:\n{}", ps.len(), dump_polygons(&ps, eps)))
+        }
+    }
+}
+
+// Ear clips the projected loops and checks every triangle against the face normal.
+// Flipped triangles are retried with a coarser epsilon, which merges more of the
+// near-degenerate features, and None is returned if none of the attempts is valid.
+pub(crate) fn triangulate_checked<F>(
+    polys: &[Vec<Pt>],
+    pos: F,
+    n: &Vec3,
+    eps: Real,
+    delaunay: bool,
+) -> Option<Vec<Vec3u>> where F: Fn(usize) -> Vec3 {
+    let proj = get_aa_proj_matrix(n);
+    for scale in [1., 10., 100.] {
+        let e = eps * scale;
+        let mut tris = EarClip::new(polys, e).triangulate();
+        if delaunay { delaunay_flip(&mut tris, |i| compute_aa_proj(&proj, &pos(i)), e); }
+        if tris.iter().all(|t| is_ccw_3d(&pos(t.x), &pos(t.y), &pos(t.z), n, e) >= 0) { return Some(tris); }
+    }
+    None
 }
 
 
@@ -212,3 +244,74 @@ fn update_reference(
 
 
 
+
+/// Triangulates polygons with holes, where outer loops are ccw and holes cw.
+/// The triangles are ccw and index into the concatenated loops. A negative `eps`
+/// derives the epsilon from the bounding box of the polygons.
+pub fn triangulate_polygons(polys: &[Vec<Vec2>], eps: Real) -> Vec<[usize; 3]> {
+    let mut oft = 0;
+    let pts = polys.iter().map(|p| {
+        let r = p.iter().enumerate().map(|(i, &pos)| Pt { pos, idx: oft + i }).collect::<Vec<_>>();
+        oft += p.len();
+        r
+    }).collect::<Vec<_>>();
+    EarClip::new(&pts, eps).triangulate().iter().map(|t| [t.x, t.y, t.z]).collect()
+}
+
+/// Triangulates planar polygons in 3D, projected along the dominant axis of their Newell normal.
+/// The loops are oriented as in `triangulate_polygons` when seen against the normal,
+/// and the triangles wind the same way.
+pub fn triangulate_polygons_3d(polys: &[Vec<Vec3>], eps: Real) -> Vec<[usize; 3]> {
+    let mut n = Vec3::ZERO;
+    for p in polys.iter() {
+        for i in 0..p.len() { n += p[i].cross(p[(i + 1) % p.len()]); }
+    }
+    let proj = get_aa_proj_matrix(&n);
+    let polys = polys.iter()
+        .map(|p| p.iter().map(|v| compute_aa_proj(&proj, v)).collect())
+        .collect::<Vec<_>>();
+    triangulate_polygons(&polys, eps)
+}
+
+/// Writes polygons in a plain text format that `load_polygons` reads back bit-exactly:
+/// an `eps` line, then a `loop <n>` line followed by n `x y` lines for each loop.
+pub fn dump_polygons(polys: &[Vec<Vec2>], eps: Real) -> String {
+    let mut s = format!("eps {eps}\n");
+    for p in polys.iter() {
+        s += &format!("loop {}\n", p.len());
+        for v in p.iter() { s += &format!("{} {}\n", v.x, v.y); }
+    }
+    s
+}
+
+/// Reads polygons written by `dump_polygons`, which can be passed on to `triangulate_polygons`.
+/// Fails if a loop does not have the number of points its header announces.
+pub fn load_polygons(s: &str) -> Result<(Vec<Vec<Vec2>>, Real), String> {
+    let num = |t: Option<&str>| t
+        .ok_or("unexpected end of line".to_string())?
+        .parse::<Real>()
+        .map_err(|e| e.to_string());
+    let check = |p: &[Vec2], n: usize| {
+        if p.len() == n { Ok(()) } else { Err(format!("loop has {} points instead of {n}", p.len())) }
+    };
+    let mut eps = -1.;
+    let mut polys: Vec<Vec<Vec2>> = vec![];
+    let mut lens = vec![];
+    for line in s.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        let mut ts = line.split_whitespace();
+        match ts.next() {
+            Some("eps") => eps = num(ts.next())?,
+            Some("loop") => {
+                if let (Some(p), Some(&n)) = (polys.last(), lens.last()) { check(p, n)?; }
+                lens.push(ts.next().ok_or("missing loop size")?.parse::<usize>().map_err(|e| e.to_string())?);
+                polys.push(vec![]);
+            }
+            t => {
+                let p = Vec2::new(num(t)?, num(ts.next())?);
+                polys.last_mut().ok_or("point given before any loop")?.push(p);
+            }
+        }
+    }
+    if let (Some(p), Some(&n)) = (polys.last(), lens.last()) { check(p, n)?; }
+    Ok((polys, eps))
+}